@@ -0,0 +1,75 @@
+use std::hint::black_box;
+
+use const_lookup_map::{ConstInterpolationLookup, ConstLookup};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Nearly uniform keys, like code tables that leave a few codes unused.
+const fn keys<const N: usize>() -> [u32; N] {
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = i as u32 * 16 + (i as u32 * 7) % 11;
+        i += 1;
+    }
+    keys
+}
+
+const fn values<const N: usize>() -> [u32; N] {
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = i as u32;
+        i += 1;
+    }
+    values
+}
+
+static SORTED_1K: ConstLookup<1024, u32, u32> = ConstLookup::new(keys(), values());
+static INTERPOLATION_1K: ConstInterpolationLookup<1024, u32, u32> =
+    ConstInterpolationLookup::new(ConstLookup::new(keys(), values()));
+
+static SORTED_64K: ConstLookup<65536, u32, u32> = ConstLookup::new(keys(), values());
+static INTERPOLATION_64K: ConstInterpolationLookup<65536, u32, u32> =
+    ConstInterpolationLookup::new(ConstLookup::new(keys(), values()));
+
+fn bench_size<const N: usize>(
+    c: &mut Criterion,
+    sorted: &ConstLookup<N, u32, u32>,
+    interpolation: &ConstInterpolationLookup<N, u32, u32>,
+) {
+    // every 7th key, so the probes are spread over the table
+    let probes: Vec<u32> = sorted.keys.iter().step_by(7).copied().collect();
+    let mut group = c.benchmark_group("u32 get");
+
+    group.bench_with_input(
+        BenchmarkId::new("binary search", N),
+        &probes,
+        |b, probes| {
+            b.iter(|| {
+                for key in probes {
+                    black_box(sorted.get(black_box(key)));
+                }
+            })
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("interpolation", N),
+        &probes,
+        |b, probes| {
+            b.iter(|| {
+                for key in probes {
+                    black_box(interpolation.get(black_box(key)));
+                }
+            })
+        },
+    );
+    group.finish();
+}
+
+fn interpolation(c: &mut Criterion) {
+    bench_size(c, &SORTED_1K, &INTERPOLATION_1K);
+    bench_size(c, &SORTED_64K, &INTERPOLATION_64K);
+}
+
+criterion_group!(benches, interpolation);
+criterion_main!(benches);
@@ -0,0 +1,556 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::hint::black_box;
+use std::thread;
+
+use const_lookup_map::{
+    ConstDenseLookup, ConstEytzingerLookup, ConstHashLookup, ConstInterpolationLookup, ConstKey,
+    ConstLookup, KeyHash,
+};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
+
+/// Number of keys looked up in one iteration of a benchmark.
+const PROBES: usize = 64;
+
+/// Largest table, the `&str` keys of every table are taken from the start of its text.
+const MAX: usize = 100_000;
+const KEY_LEN: usize = 8;
+
+/// The keys are the even numbers, so the odd numbers in between can be used for misses.
+const fn u32_keys<const N: usize>() -> [u32; N] {
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = i as u32 * 2;
+        i += 1;
+    }
+    keys
+}
+
+const fn pair_keys<const N: usize>() -> [(u32, u32); N] {
+    let mut keys = [(0, 0); N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = (i as u32 / 8, i as u32 % 8 * 2);
+        i += 1;
+    }
+    keys
+}
+
+/// The even numbers written with [`KEY_LEN`] digits, one after the other.
+static TEXT: [u8; MAX * KEY_LEN] = {
+    let mut text = [b'0'; MAX * KEY_LEN];
+    let mut i = 0;
+    while i < MAX {
+        let mut number = i * 2;
+        let mut digit = KEY_LEN;
+        while number > 0 {
+            digit -= 1;
+            text[i * KEY_LEN + digit] = b'0' + (number % 10) as u8;
+            number /= 10;
+        }
+        i += 1;
+    }
+    text
+};
+
+/// The same numbers as [`u32_keys`], zero padded so they sort the same as strings.
+const fn str_keys<const N: usize>(text: &'static [u8; MAX * KEY_LEN]) -> [&'static str; N] {
+    assert!(N <= MAX);
+    let mut keys = [""; N];
+    let mut i = 0;
+    while i < N {
+        // SAFETY: the text holds `MAX` keys of `KEY_LEN` ASCII digits.
+        keys[i] = unsafe {
+            core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+                text.as_ptr().add(i * KEY_LEN),
+                KEY_LEN,
+            ))
+        };
+        i += 1;
+    }
+    keys
+}
+
+const fn values<const N: usize>() -> [u32; N] {
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        values[i] = i as u32;
+        i += 1;
+    }
+    values
+}
+
+/// Declares the same entries as a `ConstLookup` and, if named, a `ConstEytzingerLookup` and a `ConstHashLookup`.
+macro_rules! tables {
+    ($($lookup:ident $(, $eytzinger:ident, $hash:ident)?: [$key:ty; $n:expr] = $keys:expr;)*) => {
+        $(
+            static $lookup: ConstLookup<$n, $key, u32> = ConstLookup::new($keys, values());
+            $(
+                static $eytzinger: ConstEytzingerLookup<$n, $key, u32> =
+                    ConstEytzingerLookup::new(ConstLookup::new($keys, values()));
+                static $hash: ConstHashLookup<$n, $key, u32> = ConstHashLookup::new($keys, values());
+            )?
+        )*
+    };
+}
+
+tables! {
+    U32_4, U32_EYTZINGER_4, U32_HASH_4: [u32; 4] = u32_keys();
+    U32_64, U32_EYTZINGER_64, U32_HASH_64: [u32; 64] = u32_keys();
+    U32_1K, U32_EYTZINGER_1K, U32_HASH_1K: [u32; 1024] = u32_keys();
+    U32_100K: [u32; MAX] = u32_keys();
+
+    PAIR_4, PAIR_EYTZINGER_4, PAIR_HASH_4: [(u32, u32); 4] = pair_keys();
+    PAIR_64, PAIR_EYTZINGER_64, PAIR_HASH_64: [(u32, u32); 64] = pair_keys();
+    PAIR_1K, PAIR_EYTZINGER_1K, PAIR_HASH_1K: [(u32, u32); 1024] = pair_keys();
+    PAIR_100K: [(u32, u32); MAX] = pair_keys();
+
+    STR_4, STR_EYTZINGER_4, STR_HASH_4: [&str; 4] = str_keys(&TEXT);
+    STR_64, STR_EYTZINGER_64, STR_HASH_64: [&str; 64] = str_keys(&TEXT);
+    STR_1K, STR_EYTZINGER_1K, STR_HASH_1K: [&str; 1024] = str_keys(&TEXT);
+    STR_100K: [&str; MAX] = str_keys(&TEXT);
+}
+
+/// Builds a table when the benchmark starts, for the largest `ConstEytzingerLookup` and `ConstHashLookup`.
+///
+/// Sorting 100k keys into Eytzinger order or finding their perfect hash function takes from seconds to minutes in a
+/// const context, at runtime it is fast. It runs on its own thread because the arrays do not fit on the stack of the
+/// main thread.
+fn runtime_table<T: Send + Sync + 'static>(build: fn() -> T) -> &'static T {
+    thread::Builder::new()
+        .stack_size(256 << 20)
+        .spawn(move || &*Box::leak(Box::new(build())))
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+/// Declares the same entries as a `ConstInterpolationLookup` and a `ConstDenseLookup`, which only take integer keys.
+macro_rules! integer_tables {
+    ($($interpolation:ident, $dense:ident: [$key:ty; $n:expr] = $keys:expr;)*) => {
+        $(
+            static $interpolation: ConstInterpolationLookup<$n, $key, u32> =
+                ConstInterpolationLookup::new(ConstLookup::new($keys, values()));
+            static $dense: ConstDenseLookup<$n, $key, u32> =
+                ConstDenseLookup::new(ConstLookup::new($keys, values()));
+        )*
+    };
+}
+
+integer_tables! {
+    U32_INTERPOLATION_4, U32_DENSE_4: [u32; 4] = u32_keys();
+    U32_INTERPOLATION_64, U32_DENSE_64: [u32; 64] = u32_keys();
+    U32_INTERPOLATION_1K, U32_DENSE_1K: [u32; 1024] = u32_keys();
+    U32_INTERPOLATION_100K, U32_DENSE_100K: [u32; MAX] = u32_keys();
+}
+
+/// The other maps of the crate, holding the same entries as the `ConstLookup` they are compared with.
+struct Strategies<'a, const N: usize, K: Ord> {
+    hash: &'a ConstHashLookup<N, K, u32>,
+    eytzinger: &'a ConstEytzingerLookup<N, K, u32>,
+    /// Only for integer keys.
+    interpolation: Option<&'a ConstInterpolationLookup<N, K, u32>>,
+    /// Only for integer keys, which have gaps so it falls back to a binary search.
+    dense: Option<&'a ConstDenseLookup<N, K, u32>>,
+}
+
+/// Writes a `match` by hand, as it would be without a lookup table.
+macro_rules! match_fn {
+    ($name:ident($key:ty) { $($pattern:pat => $value:expr,)* }) => {
+        fn $name(key: &$key) -> Option<u32> {
+            match *key {
+                $($pattern => Some($value),)*
+                _ => None,
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+match_fn!(match_u32_4(u32) {
+    0 => 0, 2 => 1, 4 => 2, 6 => 3,
+});
+
+#[rustfmt::skip]
+match_fn!(match_u32_64(u32) {
+    0 => 0, 2 => 1, 4 => 2, 6 => 3, 8 => 4, 10 => 5, 12 => 6, 14 => 7,
+    16 => 8, 18 => 9, 20 => 10, 22 => 11, 24 => 12, 26 => 13, 28 => 14, 30 => 15,
+    32 => 16, 34 => 17, 36 => 18, 38 => 19, 40 => 20, 42 => 21, 44 => 22, 46 => 23,
+    48 => 24, 50 => 25, 52 => 26, 54 => 27, 56 => 28, 58 => 29, 60 => 30, 62 => 31,
+    64 => 32, 66 => 33, 68 => 34, 70 => 35, 72 => 36, 74 => 37, 76 => 38, 78 => 39,
+    80 => 40, 82 => 41, 84 => 42, 86 => 43, 88 => 44, 90 => 45, 92 => 46, 94 => 47,
+    96 => 48, 98 => 49, 100 => 50, 102 => 51, 104 => 52, 106 => 53, 108 => 54, 110 => 55,
+    112 => 56, 114 => 57, 116 => 58, 118 => 59, 120 => 60, 122 => 61, 124 => 62, 126 => 63,
+});
+
+#[rustfmt::skip]
+match_fn!(match_pair_4((u32, u32)) {
+    (0, 0) => 0, (0, 2) => 1, (0, 4) => 2, (0, 6) => 3,
+});
+
+#[rustfmt::skip]
+match_fn!(match_pair_64((u32, u32)) {
+    (0, 0) => 0, (0, 2) => 1, (0, 4) => 2, (0, 6) => 3,
+    (0, 8) => 4, (0, 10) => 5, (0, 12) => 6, (0, 14) => 7,
+    (1, 0) => 8, (1, 2) => 9, (1, 4) => 10, (1, 6) => 11,
+    (1, 8) => 12, (1, 10) => 13, (1, 12) => 14, (1, 14) => 15,
+    (2, 0) => 16, (2, 2) => 17, (2, 4) => 18, (2, 6) => 19,
+    (2, 8) => 20, (2, 10) => 21, (2, 12) => 22, (2, 14) => 23,
+    (3, 0) => 24, (3, 2) => 25, (3, 4) => 26, (3, 6) => 27,
+    (3, 8) => 28, (3, 10) => 29, (3, 12) => 30, (3, 14) => 31,
+    (4, 0) => 32, (4, 2) => 33, (4, 4) => 34, (4, 6) => 35,
+    (4, 8) => 36, (4, 10) => 37, (4, 12) => 38, (4, 14) => 39,
+    (5, 0) => 40, (5, 2) => 41, (5, 4) => 42, (5, 6) => 43,
+    (5, 8) => 44, (5, 10) => 45, (5, 12) => 46, (5, 14) => 47,
+    (6, 0) => 48, (6, 2) => 49, (6, 4) => 50, (6, 6) => 51,
+    (6, 8) => 52, (6, 10) => 53, (6, 12) => 54, (6, 14) => 55,
+    (7, 0) => 56, (7, 2) => 57, (7, 4) => 58, (7, 6) => 59,
+    (7, 8) => 60, (7, 10) => 61, (7, 12) => 62, (7, 14) => 63,
+});
+
+#[rustfmt::skip]
+match_fn!(match_str_4(&str) {
+    "00000000" => 0, "00000002" => 1, "00000004" => 2, "00000006" => 3,
+});
+
+#[rustfmt::skip]
+match_fn!(match_str_64(&str) {
+    "00000000" => 0, "00000002" => 1, "00000004" => 2, "00000006" => 3,
+    "00000008" => 4, "00000010" => 5, "00000012" => 6, "00000014" => 7,
+    "00000016" => 8, "00000018" => 9, "00000020" => 10, "00000022" => 11,
+    "00000024" => 12, "00000026" => 13, "00000028" => 14, "00000030" => 15,
+    "00000032" => 16, "00000034" => 17, "00000036" => 18, "00000038" => 19,
+    "00000040" => 20, "00000042" => 21, "00000044" => 22, "00000046" => 23,
+    "00000048" => 24, "00000050" => 25, "00000052" => 26, "00000054" => 27,
+    "00000056" => 28, "00000058" => 29, "00000060" => 30, "00000062" => 31,
+    "00000064" => 32, "00000066" => 33, "00000068" => 34, "00000070" => 35,
+    "00000072" => 36, "00000074" => 37, "00000076" => 38, "00000078" => 39,
+    "00000080" => 40, "00000082" => 41, "00000084" => 42, "00000086" => 43,
+    "00000088" => 44, "00000090" => 45, "00000092" => 46, "00000094" => 47,
+    "00000096" => 48, "00000098" => 49, "00000100" => 50, "00000102" => 51,
+    "00000104" => 52, "00000106" => 53, "00000108" => 54, "00000110" => 55,
+    "00000112" => 56, "00000114" => 57, "00000116" => 58, "00000118" => 59,
+    "00000120" => 60, "00000122" => 61, "00000124" => 62, "00000126" => 63,
+});
+
+/// Looks up all the probes with `get` in one iteration.
+fn bench_probes<K, R>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    name: &str,
+    n: usize,
+    probes: &[K],
+    get: impl Fn(&K) -> R,
+) {
+    group.bench_with_input(BenchmarkId::new(name, n), probes, |b, probes| {
+        b.iter(|| {
+            for key in probes {
+                black_box(get(black_box(key)));
+            }
+        })
+    });
+}
+
+/// Benchmarks one table against the same entries in the other maps of the crate, a `BTreeMap`, a `HashMap` and, for
+/// the small tables, a `match`.
+fn bench_table<const N: usize, K>(
+    c: &mut Criterion,
+    name: &str,
+    lookup: &ConstLookup<N, K, u32>,
+    strategies: Strategies<N, K>,
+    miss: impl Fn(&K) -> K,
+    matcher: Option<fn(&K) -> Option<u32>>,
+) where
+    K: Ord + Hash + Copy + ConstKey + KeyHash,
+{
+    assert!(lookup.check_sorted());
+    let btree: BTreeMap<K, u32> = lookup.iter().map(|(key, value)| (*key, *value)).collect();
+    let hash: HashMap<K, u32> = lookup.iter().map(|(key, value)| (*key, *value)).collect();
+
+    // spread over the table, in an order that the branch predictor cannot learn
+    let hits: Vec<K> = (0..PROBES).map(|i| lookup.keys[i * 7919 % N]).collect();
+    let misses: Vec<K> = hits.iter().map(miss).collect();
+    for key in hits.iter().chain(&misses) {
+        let expected = lookup.get(key);
+        assert_eq!(strategies.hash.get(key), expected);
+        assert_eq!(strategies.eytzinger.get(key), expected);
+        if let Some(interpolation) = strategies.interpolation {
+            assert_eq!(interpolation.get(key), expected);
+        }
+        if let Some(dense) = strategies.dense {
+            assert_eq!(dense.get(key), expected);
+        }
+        if let Some(matcher) = matcher {
+            assert_eq!(matcher(key), expected.copied());
+        }
+    }
+
+    // half hits and half misses
+    let mixed: Vec<K> = hits
+        .iter()
+        .zip(&misses)
+        .flat_map(|(hit, miss)| [*hit, *miss])
+        .take(PROBES)
+        .collect();
+
+    for (group, probes) in [("get hit", &hits), ("get miss", &misses)] {
+        let mut group = c.benchmark_group(format!("{name} {group}"));
+        bench_probes(&mut group, "ConstLookup", N, probes, |key| lookup.get(key));
+        bench_probes(&mut group, "ConstHashLookup", N, probes, |key| {
+            strategies.hash.get(key)
+        });
+        bench_probes(&mut group, "ConstEytzingerLookup", N, probes, |key| {
+            strategies.eytzinger.get(key)
+        });
+        if let Some(interpolation) = strategies.interpolation {
+            bench_probes(&mut group, "ConstInterpolationLookup", N, probes, |key| {
+                interpolation.get(key)
+            });
+        }
+        if let Some(dense) = strategies.dense {
+            bench_probes(&mut group, "ConstDenseLookup", N, probes, |key| {
+                dense.get(key)
+            });
+        }
+        bench_probes(&mut group, "BTreeMap", N, probes, |key| btree.get(key));
+        bench_probes(&mut group, "HashMap", N, probes, |key| hash.get(key));
+        if let Some(matcher) = matcher {
+            bench_probes(&mut group, "match", N, probes, matcher);
+        }
+        group.finish();
+    }
+
+    let mut group = c.benchmark_group(format!("{name} contains_key"));
+    bench_probes(&mut group, "ConstLookup", N, &mixed, |key| {
+        lookup.contains_key(key)
+    });
+    bench_probes(&mut group, "ConstHashLookup", N, &mixed, |key| {
+        strategies.hash.contains_key(key)
+    });
+    bench_probes(&mut group, "ConstEytzingerLookup", N, &mixed, |key| {
+        strategies.eytzinger.contains_key(key)
+    });
+    if let Some(interpolation) = strategies.interpolation {
+        bench_probes(&mut group, "ConstInterpolationLookup", N, &mixed, |key| {
+            interpolation.contains_key(key)
+        });
+    }
+    if let Some(dense) = strategies.dense {
+        bench_probes(&mut group, "ConstDenseLookup", N, &mixed, |key| {
+            dense.contains_key(key)
+        });
+    }
+    bench_probes(&mut group, "BTreeMap", N, &mixed, |key| {
+        btree.contains_key(key)
+    });
+    bench_probes(&mut group, "HashMap", N, &mixed, |key| {
+        hash.contains_key(key)
+    });
+    if let Some(matcher) = matcher {
+        bench_probes(&mut group, "match", N, &mixed, |key| matcher(key).is_some());
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group(format!("{name} index"));
+    bench_probes(&mut group, "ConstLookup", N, &hits, |key| lookup[key]);
+    bench_probes(&mut group, "ConstHashLookup", N, &hits, |key| {
+        strategies.hash[key]
+    });
+    bench_probes(&mut group, "ConstEytzingerLookup", N, &hits, |key| {
+        strategies.eytzinger[key]
+    });
+    if let Some(interpolation) = strategies.interpolation {
+        bench_probes(&mut group, "ConstInterpolationLookup", N, &hits, |key| {
+            interpolation[key]
+        });
+    }
+    if let Some(dense) = strategies.dense {
+        bench_probes(&mut group, "ConstDenseLookup", N, &hits, |key| dense[key]);
+    }
+    bench_probes(&mut group, "BTreeMap", N, &hits, |key| btree[key]);
+    bench_probes(&mut group, "HashMap", N, &hits, |key| hash[key]);
+    group.finish();
+}
+
+fn u32_keys_bench(c: &mut Criterion) {
+    let miss = |key: &u32| key + 1;
+    bench_table(
+        c,
+        "u32",
+        &U32_4,
+        Strategies {
+            hash: &U32_HASH_4,
+            eytzinger: &U32_EYTZINGER_4,
+            interpolation: Some(&U32_INTERPOLATION_4),
+            dense: Some(&U32_DENSE_4),
+        },
+        miss,
+        Some(match_u32_4),
+    );
+    bench_table(
+        c,
+        "u32",
+        &U32_64,
+        Strategies {
+            hash: &U32_HASH_64,
+            eytzinger: &U32_EYTZINGER_64,
+            interpolation: Some(&U32_INTERPOLATION_64),
+            dense: Some(&U32_DENSE_64),
+        },
+        miss,
+        Some(match_u32_64),
+    );
+    bench_table(
+        c,
+        "u32",
+        &U32_1K,
+        Strategies {
+            hash: &U32_HASH_1K,
+            eytzinger: &U32_EYTZINGER_1K,
+            interpolation: Some(&U32_INTERPOLATION_1K),
+            dense: Some(&U32_DENSE_1K),
+        },
+        miss,
+        None,
+    );
+    bench_table(
+        c,
+        "u32",
+        &U32_100K,
+        Strategies {
+            hash: runtime_table(|| ConstHashLookup::new(U32_100K.keys, U32_100K.values)),
+            eytzinger: runtime_table(|| {
+                ConstEytzingerLookup::new(ConstLookup::new(U32_100K.keys, U32_100K.values))
+            }),
+            interpolation: Some(&U32_INTERPOLATION_100K),
+            dense: Some(&U32_DENSE_100K),
+        },
+        miss,
+        None,
+    );
+}
+
+fn pair_keys_bench(c: &mut Criterion) {
+    let miss = |&(first, second): &(u32, u32)| (first, second + 1);
+    bench_table(
+        c,
+        "(u32, u32)",
+        &PAIR_4,
+        Strategies {
+            hash: &PAIR_HASH_4,
+            eytzinger: &PAIR_EYTZINGER_4,
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        Some(match_pair_4),
+    );
+    bench_table(
+        c,
+        "(u32, u32)",
+        &PAIR_64,
+        Strategies {
+            hash: &PAIR_HASH_64,
+            eytzinger: &PAIR_EYTZINGER_64,
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        Some(match_pair_64),
+    );
+    bench_table(
+        c,
+        "(u32, u32)",
+        &PAIR_1K,
+        Strategies {
+            hash: &PAIR_HASH_1K,
+            eytzinger: &PAIR_EYTZINGER_1K,
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        None,
+    );
+    bench_table(
+        c,
+        "(u32, u32)",
+        &PAIR_100K,
+        Strategies {
+            hash: runtime_table(|| ConstHashLookup::new(PAIR_100K.keys, PAIR_100K.values)),
+            eytzinger: runtime_table(|| {
+                ConstEytzingerLookup::new(ConstLookup::new(PAIR_100K.keys, PAIR_100K.values))
+            }),
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        None,
+    );
+}
+
+fn str_keys_bench(c: &mut Criterion) {
+    // the odd number after the key, with the same length so the comparisons do the same work
+    let miss = |key: &&str| -> &'static str {
+        let number: u32 = key.parse().unwrap();
+        Box::leak(format!("{:0width$}", number + 1, width = KEY_LEN).into_boxed_str())
+    };
+    bench_table(
+        c,
+        "&str",
+        &STR_4,
+        Strategies {
+            hash: &STR_HASH_4,
+            eytzinger: &STR_EYTZINGER_4,
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        Some(match_str_4),
+    );
+    bench_table(
+        c,
+        "&str",
+        &STR_64,
+        Strategies {
+            hash: &STR_HASH_64,
+            eytzinger: &STR_EYTZINGER_64,
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        Some(match_str_64),
+    );
+    bench_table(
+        c,
+        "&str",
+        &STR_1K,
+        Strategies {
+            hash: &STR_HASH_1K,
+            eytzinger: &STR_EYTZINGER_1K,
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        None,
+    );
+    bench_table(
+        c,
+        "&str",
+        &STR_100K,
+        Strategies {
+            hash: runtime_table(|| ConstHashLookup::new(STR_100K.keys, STR_100K.values)),
+            eytzinger: runtime_table(|| {
+                ConstEytzingerLookup::new(ConstLookup::new(STR_100K.keys, STR_100K.values))
+            }),
+            interpolation: None,
+            dense: None,
+        },
+        miss,
+        None,
+    );
+}
+
+criterion_group!(benches, u32_keys_bench, pair_keys_bench, str_keys_bench);
+criterion_main!(benches);
@@ -0,0 +1,127 @@
+use std::hint::black_box;
+
+use const_lookup_map::ConstLookup;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const fn u32_lookup<const N: usize>() -> ConstLookup<N, u32, u32> {
+    let mut keys = [0; N];
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = i as u32 * 3;
+        values[i] = i as u32;
+        i += 1;
+    }
+    ConstLookup::new(keys, values)
+}
+
+const fn u8_lookup<const N: usize>() -> ConstLookup<N, u8, u32> {
+    let mut keys = [0; N];
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = i as u8 * 3;
+        values[i] = i as u32;
+        i += 1;
+    }
+    ConstLookup::new(keys, values)
+}
+
+const WORDS: [&str; 64] = [
+    "able", "acid", "aged", "also", "area", "army", "away", "baby", "back", "ball", "band", "bank",
+    "base", "bath", "bear", "beat", "been", "beer", "bell", "belt", "best", "bill", "bird", "blow",
+    "blue", "boat", "body", "bomb", "bond", "bone", "book", "boom", "born", "boss", "both", "bowl",
+    "bulk", "burn", "bush", "busy", "call", "calm", "came", "camp", "card", "care", "case", "cash",
+    "cast", "cell", "chat", "chip", "city", "club", "coal", "coat", "code", "cold", "come", "cook",
+    "cool", "cope", "copy", "core",
+];
+
+const fn str_lookup<const N: usize>() -> ConstLookup<N, &'static str, u32> {
+    let mut keys = [""; N];
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = WORDS[i];
+        values[i] = i as u32;
+        i += 1;
+    }
+    ConstLookup::new(keys, values)
+}
+
+/// Stops at the first key that is not smaller, `get` counts the smaller keys instead.
+fn early_exit_search<K: Ord>(keys: &[K], key: &K) -> Option<usize> {
+    keys.iter()
+        .position(|probe| probe >= key)
+        .filter(|&index| keys[index] == *key)
+}
+
+fn bench_size<const N: usize, K: Ord + Copy>(
+    c: &mut Criterion,
+    name: &str,
+    lookup: ConstLookup<N, K, u32>,
+    probes: &[K],
+) {
+    let mut group = c.benchmark_group(name);
+    group.bench_with_input(BenchmarkId::new("binary search", N), probes, |b, probes| {
+        b.iter(|| {
+            for key in probes {
+                black_box(lookup.keys.binary_search(black_box(key)).ok());
+            }
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("early exit", N), probes, |b, probes| {
+        b.iter(|| {
+            for key in probes {
+                black_box(early_exit_search(&lookup.keys, black_box(key)));
+            }
+        })
+    });
+    group.bench_with_input(BenchmarkId::new("get", N), probes, |b, probes| {
+        b.iter(|| {
+            for key in probes {
+                black_box(lookup.get(black_box(key)));
+            }
+        })
+    });
+    group.finish();
+}
+
+fn u32_size<const N: usize>(c: &mut Criterion) {
+    // hits and misses, in an order that the branch predictor cannot learn
+    let probes: Vec<u32> = (0..64).map(|i| (i * 37 % (3 * N)) as u32).collect();
+    bench_size(c, "u32 small get", u32_lookup::<N>(), &probes);
+}
+
+fn u8_size<const N: usize>(c: &mut Criterion) {
+    let probes: Vec<u8> = (0..64).map(|i| (i * 37 % (3 * N)) as u8).collect();
+    bench_size(c, "u8 small get", u8_lookup::<N>(), &probes);
+}
+
+fn str_size<const N: usize>(c: &mut Criterion) {
+    let probes: Vec<&str> = (0..64).map(|i| WORDS[i * 37 % WORDS.len()]).collect();
+    bench_size(c, "&str small get", str_lookup::<N>(), &probes);
+}
+
+fn small(c: &mut Criterion) {
+    u32_size::<2>(c);
+    u32_size::<4>(c);
+    u32_size::<8>(c);
+    u32_size::<16>(c);
+    u32_size::<32>(c);
+    u32_size::<64>(c);
+    u8_size::<2>(c);
+    u8_size::<4>(c);
+    u8_size::<8>(c);
+    u8_size::<16>(c);
+    u8_size::<32>(c);
+    u8_size::<64>(c);
+    str_size::<2>(c);
+    str_size::<4>(c);
+    str_size::<8>(c);
+    str_size::<16>(c);
+    str_size::<32>(c);
+    str_size::<64>(c);
+}
+
+criterion_group!(benches, small);
+criterion_main!(benches);
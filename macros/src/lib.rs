@@ -0,0 +1,300 @@
+//! Procedural macros for [`const_lookup_map`](https://docs.rs/const_lookup_map), use them through its `macros`
+//! feature.
+
+use std::cmp::{Ordering, Reverse};
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    braced, parse_macro_input, Attribute, Expr, ExprLit, ExprUnary, Ident, Lit, Token, Type, UnOp,
+    Visibility,
+};
+
+/// Creates a `ConstLookup` from literal keys, the keys are sorted and checked for duplicates while compiling.
+///
+/// Keys have to be string, integer, `char` or `bool` literals, all of the same kind. Values can be any expression.
+///
+/// The declaring form writes the complete `const` or `static` item, including the length of the lookup:
+///
+/// ```rust
+/// use const_lookup_map_macros::const_lookup;
+///
+/// const_lookup! {
+///     pub const STATUS: u16 => &str = {
+///         404 => "Not Found",
+///         200 => "OK",
+///         500 => "Internal Server Error",
+///     };
+/// }
+///
+/// assert_eq!(STATUS.len(), 3);
+/// assert_eq!(STATUS.keys, [200, 404, 500]);
+/// assert_eq!(STATUS[&404], "Not Found");
+/// ```
+///
+/// Without a declaration it creates just the lookup, like `lookup!`:
+///
+/// ```rust
+/// use const_lookup_map::ConstLookup;
+/// use const_lookup_map_macros::const_lookup;
+///
+/// const LOOKUP: ConstLookup<2, &str, u8> = const_lookup! {
+///     "two" => 2,
+///     "one" => 1,
+/// };
+///
+/// assert_eq!(LOOKUP.keys, ["one", "two"]);
+/// ```
+///
+/// Duplicate keys are an error that points at the second entry:
+///
+/// ```rust,compile_fail
+/// use const_lookup_map_macros::const_lookup;
+///
+/// const_lookup! {
+///     const LOOKUP: &str => u8 = {
+///         "one" => 1,
+///         "one" => 2,
+///     };
+/// }
+/// ```
+#[proc_macro]
+pub fn const_lookup(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as Input);
+    let expanded = match input {
+        Input::Items(items) => items
+            .iter()
+            .map(Item::expand)
+            .collect::<syn::Result<proc_macro2::TokenStream>>(),
+        Input::Entries(entries) => expand_entries(&entries).map(|(_, lookup)| lookup),
+    };
+
+    expanded
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+enum Input {
+    Items(Vec<Item>),
+    Entries(Entries),
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        fork.call(Attribute::parse_outer)?;
+        fork.parse::<Visibility>()?;
+        if fork.peek(Token![const]) || fork.peek(Token![static]) {
+            let mut items = Vec::new();
+            while !input.is_empty() {
+                items.push(input.parse()?);
+            }
+            Ok(Input::Items(items))
+        } else {
+            input.call(Entries::parse_terminated).map(Input::Entries)
+        }
+    }
+}
+
+/// `#[attrs] pub const NAME: Key => Value = { entries };`
+struct Item {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    kind: proc_macro2::TokenStream,
+    name: Ident,
+    key_type: Type,
+    value_type: Type,
+    entries: Entries,
+}
+
+impl Parse for Item {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let kind = if input.peek(Token![static]) {
+            input.parse::<Token![static]>()?.into_token_stream()
+        } else {
+            input.parse::<Token![const]>()?.into_token_stream()
+        };
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let key_type = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let value_type = input.parse()?;
+        input.parse::<Token![=]>()?;
+        let content;
+        braced!(content in input);
+        let entries = content.call(Entries::parse_terminated)?;
+        input.parse::<Token![;]>()?;
+
+        Ok(Item {
+            attrs,
+            vis,
+            kind,
+            name,
+            key_type,
+            value_type,
+            entries,
+        })
+    }
+}
+
+impl Item {
+    fn expand(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let Item {
+            attrs,
+            vis,
+            kind,
+            name,
+            key_type,
+            value_type,
+            ..
+        } = self;
+        let (len, lookup) = expand_entries(&self.entries)?;
+
+        Ok(quote! {
+            #(#attrs)*
+            #vis #kind #name: ::const_lookup_map::ConstLookup<#len, #key_type, #value_type> = #lookup;
+        })
+    }
+}
+
+struct Entry {
+    key: Expr,
+    value: Expr,
+}
+
+impl Parse for Entry {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let value = input.parse()?;
+        Ok(Entry { key, value })
+    }
+}
+
+type Entries = Punctuated<Entry, Token![,]>;
+
+/// The value of a literal key, ordered the same way as the type of the literal.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    Str(String),
+    Int(Int),
+    Char(char),
+    Bool(bool),
+}
+
+/// An integer literal as sign and magnitude, so every `i128` and `u128` value fits.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Int {
+    Negative(Reverse<u128>),
+    NonNegative(u128),
+}
+
+impl Key {
+    fn kind(&self) -> &'static str {
+        match self {
+            Key::Str(_) => "string",
+            Key::Int(_) => "integer",
+            Key::Char(_) => "char",
+            Key::Bool(_) => "bool",
+        }
+    }
+
+    fn from_expr(expr: &Expr) -> syn::Result<Key> {
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => Key::from_lit(lit, false),
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match &**expr {
+                Expr::Lit(ExprLit {
+                    lit: lit @ Lit::Int(_),
+                    ..
+                }) => Key::from_lit(lit, true),
+                _ => Err(not_a_literal(expr.span())),
+            },
+            Expr::Group(group) => Key::from_expr(&group.expr),
+            _ => Err(not_a_literal(expr.span())),
+        }
+    }
+
+    fn from_lit(lit: &Lit, negative: bool) -> syn::Result<Key> {
+        match lit {
+            Lit::Str(lit) => Ok(Key::Str(lit.value())),
+            Lit::Int(lit) => {
+                let magnitude = lit.base10_parse::<u128>()?;
+                Ok(Key::Int(if negative && magnitude != 0 {
+                    Int::Negative(Reverse(magnitude))
+                } else {
+                    Int::NonNegative(magnitude)
+                }))
+            }
+            Lit::Char(lit) => Ok(Key::Char(lit.value())),
+            Lit::Bool(lit) => Ok(Key::Bool(lit.value)),
+            _ => Err(not_a_literal(lit.span())),
+        }
+    }
+}
+
+fn not_a_literal(span: Span) -> syn::Error {
+    syn::Error::new(span, "key must be a string, integer, char or bool literal")
+}
+
+/// Sorts the entries and returns the length and the `ConstLookup` expression.
+fn expand_entries(entries: &Entries) -> syn::Result<(usize, proc_macro2::TokenStream)> {
+    let mut keyed = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let key = Key::from_expr(&entry.key)?;
+        if let Some((first, _)) = keyed.first() {
+            let first: &Key = first;
+            if first.kind() != key.kind() {
+                return Err(syn::Error::new(
+                    entry.key.span(),
+                    format!(
+                        "expected a {} literal like the first key, found a {} literal",
+                        first.kind(),
+                        key.kind()
+                    ),
+                ));
+            }
+        }
+        keyed.push((key, entry));
+    }
+
+    // stable, so the error for a duplicate points at the entry that comes last
+    keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut error: Option<syn::Error> = None;
+    for pair in keyed.windows(2) {
+        let ((previous_key, previous), (key, entry)) = (&pair[0], &pair[1]);
+        if previous_key.cmp(key) == Ordering::Equal {
+            let mut duplicate = syn::Error::new(
+                entry.key.span(),
+                format!("duplicate key `{}`", entry.key.to_token_stream()),
+            );
+            duplicate.combine(syn::Error::new(previous.key.span(), "first defined here"));
+            match &mut error {
+                Some(error) => error.combine(duplicate),
+                None => error = Some(duplicate),
+            }
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let keys = keyed.iter().map(|(_, entry)| &entry.key);
+    let values = keyed.iter().map(|(_, entry)| &entry.value);
+    Ok((
+        keyed.len(),
+        quote! {
+            ::const_lookup_map::ConstLookup::new([#(#keys),*], [#(#values),*])
+        },
+    ))
+}
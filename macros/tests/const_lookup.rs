@@ -0,0 +1,66 @@
+use const_lookup_map::ConstLookup;
+use const_lookup_map_macros::const_lookup;
+
+const_lookup! {
+    /// Status codes.
+    pub const STATUS: u16 => &str = {
+        500 => "Internal Server Error",
+        200 => "OK",
+        404 => "Not Found",
+    };
+
+    static NEGATIVE: i32 => char = {
+        1 => 'b',
+        -10 => 'a',
+        300 => 'c',
+    };
+
+    const EMPTY: &str => &str = {};
+}
+
+#[test]
+fn declaring_form_infers_length() {
+    let status: &ConstLookup<3, u16, &str> = &STATUS;
+
+    assert_eq!(status.keys, [200, 404, 500]);
+    assert_eq!(status.values, ["OK", "Not Found", "Internal Server Error"]);
+    assert!(EMPTY.is_empty());
+}
+
+#[test]
+fn declaring_form_sorts_negative_keys() {
+    assert_eq!(NEGATIVE.keys, [-10, 1, 300]);
+    assert_eq!(NEGATIVE.get(&-10), Some(&'a'));
+}
+
+#[test]
+fn expression_form_sorts_keys() {
+    const LOOKUP: ConstLookup<4, &str, u8> = const_lookup! {
+        "b" => 2,
+        "a" => 1,
+        "ab" => 12,
+        "B" => 0,
+    };
+    const CHARS: ConstLookup<2, char, bool> = const_lookup!('z' => true, 'a' => false);
+
+    assert_eq!(LOOKUP.keys, ["B", "a", "ab", "b"]);
+    assert!(LOOKUP.check_sorted());
+    assert_eq!(CHARS.keys, ['a', 'z']);
+}
+
+#[test]
+fn expression_form_accepts_the_full_range() {
+    const UNSIGNED: ConstLookup<3, u128, u8> = const_lookup! {
+        340282366920938463463374607431768211455 => 2,
+        0 => 0,
+        170141183460469231731687303715884105728 => 1,
+    };
+    const SIGNED: ConstLookup<3, i128, u8> = const_lookup! {
+        0 => 1,
+        170141183460469231731687303715884105727 => 2,
+        -170141183460469231731687303715884105728 => 0,
+    };
+
+    assert_eq!(UNSIGNED.keys, [0, 1 << 127, u128::MAX]);
+    assert_eq!(SIGNED.keys, [i128::MIN, 0, i128::MAX]);
+}
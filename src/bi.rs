@@ -0,0 +1,152 @@
+use core::borrow::Borrow;
+
+use crate::key::{self, ConstKey};
+use crate::ConstLookup;
+
+/// Map that can be defined in a const context and can be searched by key and by value.
+///
+/// Next to the map sorted by key, this keeps the positions of the entries sorted by value. Both are built from a
+/// single list of entries, so [`get_by_value`](ConstBiLookup::get_by_value) is a binary search just like
+/// [`get`](ConstBiLookup::get).
+///
+/// ```rust
+/// use const_lookup_map::{ConstBiLookup, bi_lookup};
+///
+/// const ERRORS: ConstBiLookup<3, u16, &str> = bi_lookup! {
+///     404 => "NotFound",
+///     200 => "Ok",
+///     500 => "InternalServerError",
+/// };
+///
+/// assert_eq!(ERRORS.get(&404), Some(&"NotFound"));
+/// assert_eq!(ERRORS.get_by_value("Ok"), Some(&200));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstBiLookup<const N: usize, K: Ord, V: Ord> {
+    lookup: ConstLookup<N, K, V>,
+    by_value: [usize; N],
+}
+
+impl<const N: usize, K: Ord + ConstKey, V: Ord + ConstKey> ConstBiLookup<N, K, V> {
+    /// Creates the map, the entries do not have to be sorted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys or the values contain duplicates.
+    pub const fn new(keys: [K; N], values: [V; N]) -> ConstBiLookup<N, K, V> {
+        let lookup = ConstLookup::new(keys, values).sorted().checked();
+        let by_value = key::sort_order(&lookup.values);
+
+        let mut i = 1;
+        while i < N {
+            assert!(
+                !key::cmp(&lookup.values[by_value[i - 1]], &lookup.values[by_value[i]]).is_eq(),
+                "duplicate value in ConstBiLookup"
+            );
+            i += 1;
+        }
+
+        ConstBiLookup { lookup, by_value }
+    }
+}
+
+impl<const N: usize, K: Ord, V: Ord> ConstBiLookup<N, K, V> {
+    /// Returns the number of elements in the map.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the map from keys to values.
+    pub const fn forward(&self) -> &ConstLookup<N, K, V> {
+        &self.lookup
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.lookup.get(key)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.lookup.contains_key(key)
+    }
+
+    fn search_value<Q>(&self, value: &Q) -> Option<usize>
+    where
+        V: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let position = self
+            .by_value
+            .binary_search_by(|&index| self.lookup.values[index].borrow().cmp(value))
+            .ok()?;
+        Some(self.by_value[position])
+    }
+
+    /// Returns a reference to the key corresponding to the value.
+    ///
+    /// The value may be any borrowed form of the map's value type, but the ordering on the borrowed form must match
+    /// the ordering on the value type.
+    pub fn get_by_value<Q>(&self, value: &Q) -> Option<&K>
+    where
+        V: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let index = self.search_value(value)?;
+        self.lookup.keys.get(index)
+    }
+
+    /// Returns true if the map contains a key for the specified value.
+    pub fn contains_value<Q>(&self, value: &Q) -> bool
+    where
+        V: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.search_value(value).is_some()
+    }
+}
+
+#[cfg(test)]
+const BI_LOOKUP: ConstBiLookup<4, &str, u16> = crate::bi_lookup! {
+    "not_found" => 404,
+    "ok" => 200,
+    "teapot" => 418,
+    "created" => 201,
+};
+
+#[test]
+fn bi_lookup_get_test() {
+    assert_eq!(BI_LOOKUP.get("teapot"), Some(&418));
+    assert_eq!(BI_LOOKUP.get("gone"), None);
+    assert!(BI_LOOKUP.contains_key("ok"));
+    assert!(BI_LOOKUP.forward().check_sorted());
+}
+
+#[test]
+fn bi_lookup_get_by_value_test() {
+    for (key, value) in BI_LOOKUP.forward() {
+        assert_eq!(BI_LOOKUP.get_by_value(value), Some(key));
+    }
+    assert_eq!(BI_LOOKUP.get_by_value(&410), None);
+    assert!(BI_LOOKUP.contains_value(&201));
+    assert!(!BI_LOOKUP.contains_value(&500));
+}
+
+#[test]
+#[should_panic(expected = "duplicate value in ConstBiLookup")]
+fn bi_lookup_rejects_duplicate_values() {
+    ConstBiLookup::new(['a', 'b', 'c'], [1, 2, 1]);
+}
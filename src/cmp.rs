@@ -0,0 +1,108 @@
+use core::cmp::Ordering;
+
+use crate::key::ConstKey;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Defines the order of the keys of a [`ConstLookup`](crate::ConstLookup).
+///
+/// The keys are sorted with the comparator and every lookup searches with it, so
+/// [`check_sorted`](crate::ConstLookup::check_sorted) and [`get`](crate::ConstLookup::get) always agree. It can be
+/// implemented for orders that `Ord` does not give, like versions compared by their numbers:
+///
+/// ```rust
+/// use std::cmp::Ordering;
+///
+/// use const_lookup_map::{Comparator, ConstLookup, lookup};
+///
+/// struct VersionCmp;
+///
+/// impl Comparator<str> for VersionCmp {
+///     fn cmp(a: &str, b: &str) -> Ordering {
+///         a.split('.')
+///             .map(|part| part.parse::<u32>().unwrap_or(0))
+///             .cmp(b.split('.').map(|part| part.parse::<u32>().unwrap_or(0)))
+///     }
+/// }
+///
+/// impl Comparator<&str> for VersionCmp {
+///     fn cmp(a: &&str, b: &&str) -> Ordering {
+///         <VersionCmp as Comparator<str>>::cmp(a, b)
+///     }
+/// }
+///
+/// const RELEASES: ConstLookup<3, &str, &str, VersionCmp> = lookup! {
+///     unsorted; cmp: VersionCmp;
+///     "1.2" => "old",
+///     "1.10" => "stable",
+///     "2.0" => "next",
+/// };
+///
+/// assert!(RELEASES.check_sorted());
+/// assert_eq!(RELEASES.get("1.10"), Some(&"stable"));
+/// ```
+///
+/// Only the comparators of this crate can sort in a const context, so `lookup!(cmp: ...)` and the declaring form
+/// `lookup! { const X: K => V, Cmp = { ... }; }` only work with [`OrdCmp`] and [`TotalCmp`]. With other comparators
+/// the entries have to be written in order, with `lookup!(unsorted; cmp: ...)`,
+/// `lookup! { unsorted; const X: K => V, Cmp = { ... }; }` or
+/// [`ConstLookup::with_comparator`](crate::ConstLookup::with_comparator), and checked with
+/// [`check_sorted`](crate::ConstLookup::check_sorted) in a test.
+pub trait Comparator<K: ?Sized> {
+    /// Compares two keys.
+    fn cmp(a: &K, b: &K) -> Ordering;
+}
+
+/// Comparators that can also sort and search in a const context, see [`ConstLookup::sorted`](crate::ConstLookup::sorted).
+///
+/// It cannot be implemented outside of this crate.
+pub trait ConstComparator<K: ConstKey>: Comparator<K> + private::Sealed {}
+
+/// Orders keys by their `Ord` implementation, the default comparator.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct OrdCmp;
+
+impl<K: ?Sized + Ord> Comparator<K> for OrdCmp {
+    fn cmp(a: &K, b: &K) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+impl private::Sealed for OrdCmp {}
+impl<K: Ord + ConstKey> ConstComparator<K> for OrdCmp {}
+
+/// Orders floats by their `total_cmp`, so they can be used as keys.
+///
+/// ```rust
+/// use const_lookup_map::{ConstLookup, TotalCmp, lookup};
+///
+/// const THRESHOLDS: ConstLookup<3, f64, &str, TotalCmp> = lookup! {
+///     cmp: TotalCmp;
+///     0.5 => "half",
+///     -1.0 => "negative",
+///     1.0 => "full",
+/// };
+///
+/// assert_eq!(THRESHOLDS.keys, [-1.0, 0.5, 1.0]);
+/// assert_eq!(THRESHOLDS.get(&0.5), Some(&"half"));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct TotalCmp;
+
+impl Comparator<f32> for TotalCmp {
+    fn cmp(a: &f32, b: &f32) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+impl Comparator<f64> for TotalCmp {
+    fn cmp(a: &f64, b: &f64) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+impl private::Sealed for TotalCmp {}
+impl ConstComparator<f32> for TotalCmp {}
+impl ConstComparator<f64> for TotalCmp {}
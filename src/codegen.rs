@@ -0,0 +1,803 @@
+//! Generates [`ConstLookup`](crate::ConstLookup) constants from data files, meant to be used from a `build.rs`.
+//!
+//! The data is read from CSV (the first column is the key, the second the value), a JSON object or a TOML table.
+//! The entries are sorted and checked for duplicate keys, so the generated constant can be used as is.
+//!
+//! ```rust,no_run
+//! // build.rs
+//! use const_lookup_map::codegen::Generator;
+//!
+//! fn main() {
+//!     let out_dir = std::env::var("OUT_DIR").unwrap();
+//!     println!("cargo:rerun-if-changed=data/status.csv");
+//!
+//!     Generator::new("STATUS")
+//!         .key_type("u16")
+//!         .write("data/status.csv", format!("{out_dir}/status.rs"))
+//!         .unwrap();
+//! }
+//! ```
+//!
+//! ```rust,ignore
+//! // src/lib.rs
+//! include!(concat!(env!("OUT_DIR"), "/status.rs"));
+//! ```
+//!
+//! This is available with the `codegen` feature.
+
+use std::cmp::Reverse;
+use std::collections::btree_map::{BTreeMap, Entry};
+use std::fmt::{self, Write as _};
+use std::path::Path;
+use std::string::{String, ToString};
+use std::vec::Vec;
+use std::{format, fs, io};
+
+/// Integer types that can be used as key or value type, with their range. The range of `usize` and `isize` depends on
+/// the target, see [`Generator::pointer_width`].
+const INTEGER_TYPES: [(&str, Int, Int); 10] = [
+    ("u8", Int::new(u8::MIN as i128), Int::new(u8::MAX as i128)),
+    (
+        "u16",
+        Int::new(u16::MIN as i128),
+        Int::new(u16::MAX as i128),
+    ),
+    (
+        "u32",
+        Int::new(u32::MIN as i128),
+        Int::new(u32::MAX as i128),
+    ),
+    (
+        "u64",
+        Int::new(u64::MIN as i128),
+        Int::new(u64::MAX as i128),
+    ),
+    (
+        "u128",
+        Int::NonNegative(u128::MIN),
+        Int::NonNegative(u128::MAX),
+    ),
+    ("i8", Int::new(i8::MIN as i128), Int::new(i8::MAX as i128)),
+    (
+        "i16",
+        Int::new(i16::MIN as i128),
+        Int::new(i16::MAX as i128),
+    ),
+    (
+        "i32",
+        Int::new(i32::MIN as i128),
+        Int::new(i32::MAX as i128),
+    ),
+    (
+        "i64",
+        Int::new(i64::MIN as i128),
+        Int::new(i64::MAX as i128),
+    ),
+    ("i128", Int::new(i128::MIN), Int::new(i128::MAX)),
+];
+
+/// The format of a data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Rows of `key,value`.
+    Csv,
+    /// An object of `"key": value`.
+    Json,
+    /// A table of `key = value`.
+    Toml,
+}
+
+impl Format {
+    /// Returns the format belonging to the extension of the path.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "csv" => Some(Format::Csv),
+            "json" => Some(Format::Json),
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur while generating a lookup.
+#[derive(Debug)]
+pub enum Error {
+    /// Reading the data file or writing the generated file failed.
+    Io(io::Error),
+    /// The extension of the data file is not `csv`, `json` or `toml`.
+    UnknownFormat(String),
+    /// The data file could not be parsed.
+    Parse(String),
+    /// The key is in the data more than once, with different values.
+    DuplicateKey(String),
+    /// The key cannot be parsed as the key type.
+    InvalidKey { key: String, key_type: String },
+    /// The value of the key is not a string, integer, float or bool, or cannot be parsed as the value type.
+    UnsupportedValue { key: String, value: String },
+    /// The key type is not supported.
+    UnsupportedKeyType(String),
+    /// The value type is not supported.
+    UnsupportedValueType(String),
+    /// The values do not all have the same type, and no value type was given.
+    MixedValueTypes { key: String },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => write!(f, "{error}"),
+            Error::UnknownFormat(path) => write!(
+                f,
+                "cannot determine the format of `{path}`, expected a csv, json or toml file"
+            ),
+            Error::Parse(message) => write!(f, "cannot parse the data: {message}"),
+            Error::DuplicateKey(key) => {
+                write!(f, "key `{key}` is defined more than once with different values")
+            }
+            Error::InvalidKey { key, key_type } => {
+                write!(f, "key `{key}` is not a valid `{key_type}`")
+            }
+            Error::UnsupportedValue { key, value } => {
+                write!(f, "value `{value}` of key `{key}` is not supported")
+            }
+            Error::UnsupportedKeyType(ty) => write!(
+                f,
+                "key type `{ty}` is not supported, use `&str` or an integer type"
+            ),
+            Error::UnsupportedValueType(ty) => write!(
+                f,
+                "value type `{ty}` is not supported, use `&str`, `bool`, `f32`, `f64` or an integer type"
+            ),
+            Error::MixedValueTypes { key } => write!(
+                f,
+                "value of key `{key}` has a different type than the values before it, set the value type"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
+
+/// An integer as sign and magnitude, so every `i128` and `u128` value fits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Int {
+    Negative(Reverse<u128>),
+    NonNegative(u128),
+}
+
+impl Int {
+    const fn new(value: i128) -> Int {
+        if value < 0 {
+            Int::Negative(Reverse(value.unsigned_abs()))
+        } else {
+            Int::NonNegative(value as u128)
+        }
+    }
+
+    fn parse(text: &str) -> Option<Int> {
+        let text = text.trim();
+        match text.strip_prefix('-') {
+            // `u128` also accepts a `+` sign, which should not follow a `-`
+            Some(magnitude) if !magnitude.starts_with('+') => match magnitude.parse().ok()? {
+                0 => Some(Int::NonNegative(0)),
+                magnitude => Some(Int::Negative(Reverse(magnitude))),
+            },
+            Some(_) => None,
+            None => text.parse().ok().map(Int::NonNegative),
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        match self {
+            Int::Negative(Reverse(magnitude)) => -(magnitude as f64),
+            Int::NonNegative(magnitude) => magnitude as f64,
+        }
+    }
+}
+
+impl fmt::Display for Int {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Int::Negative(Reverse(magnitude)) => write!(f, "-{magnitude}"),
+            Int::NonNegative(magnitude) => write!(f, "{magnitude}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Str(String),
+    Int(Int),
+    Float(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Value::Str(_) => "&str",
+            Value::Int(_) => "integer",
+            Value::Float(_) => "f64",
+            Value::Bool(_) => "bool",
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Str(value) => write!(f, "{value:?}"),
+            Value::Int(value) => write!(f, "{value}"),
+            Value::Float(value) => write!(f, "{value:?}"),
+            Value::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Key {
+    Str(String),
+    Int(Int),
+}
+
+/// Generates the source of a `ConstLookup` constant.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    name: String,
+    visibility: String,
+    key_type: String,
+    value_type: Option<String>,
+    csv_headers: bool,
+    pointer_width: Option<u32>,
+}
+
+impl Generator {
+    /// Creates a generator for a public constant with this name, with `&str` keys.
+    pub fn new(name: impl Into<String>) -> Generator {
+        Generator {
+            name: name.into(),
+            visibility: String::from("pub"),
+            key_type: String::from("&str"),
+            value_type: None,
+            csv_headers: true,
+            pointer_width: None,
+        }
+    }
+
+    /// Sets the visibility of the constant, like `pub(crate)`. Use an empty string for a private constant.
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Generator {
+        self.visibility = visibility.into();
+        self
+    }
+
+    /// Sets the key type, `&str` or an integer type like `u32`. Defaults to `&str`.
+    pub fn key_type(mut self, key_type: impl Into<String>) -> Generator {
+        self.key_type = key_type.into();
+        self
+    }
+
+    /// Sets the value type, `&str`, `bool`, `f32`, `f64` or an integer type.
+    ///
+    /// By default this is derived from the data: `&str`, `bool`, `f64`, or the first of `i64`, `u64`, `i128` and
+    /// `u128` that fits every integer. Values of CSV files are always strings, they are parsed when another value
+    /// type is set.
+    pub fn value_type(mut self, value_type: impl Into<String>) -> Generator {
+        self.value_type = Some(value_type.into());
+        self
+    }
+
+    /// Sets whether the first row of a CSV file is a header that should be skipped. Defaults to true.
+    pub fn csv_headers(mut self, csv_headers: bool) -> Generator {
+        self.csv_headers = csv_headers;
+        self
+    }
+
+    /// Sets the number of bits of `usize` and `isize` on the target, which limits the keys and values of those types.
+    ///
+    /// In a `build.rs` this defaults to the pointer width of the target that is compiled for. Elsewhere it defaults to
+    /// 16, the smallest pointer width Rust supports, so the generated code compiles for every target.
+    pub fn pointer_width(mut self, bits: u32) -> Generator {
+        assert!(
+            matches!(bits, 16 | 32 | 64),
+            "pointer width must be 16, 32 or 64"
+        );
+        self.pointer_width = Some(bits);
+        self
+    }
+
+    /// Reads the data file and writes the generated constant to `output`.
+    ///
+    /// The format is derived from the extension of `input`.
+    pub fn write(&self, input: impl AsRef<Path>, output: impl AsRef<Path>) -> Result<(), Error> {
+        let source = self.generate_file(input)?;
+        fs::write(output, source)?;
+        Ok(())
+    }
+
+    /// Reads the data file and returns the source of the generated constant.
+    ///
+    /// The format is derived from the extension of `input`.
+    pub fn generate_file(&self, input: impl AsRef<Path>) -> Result<String, Error> {
+        let input = input.as_ref();
+        let format = Format::from_path(input)
+            .ok_or_else(|| Error::UnknownFormat(input.display().to_string()))?;
+        self.generate(format, &fs::read_to_string(input)?)
+    }
+
+    /// Returns the source of the generated constant for the data.
+    ///
+    /// ```rust
+    /// use const_lookup_map::codegen::{Format, Generator};
+    ///
+    /// let source = Generator::new("PORTS")
+    ///     .generate(Format::Json, r#"{"https": 443, "http": 80}"#)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     source,
+    ///     "pub const PORTS: ::const_lookup_map::ConstLookup<2, &str, i64> = \
+    ///      ::const_lookup_map::ConstLookup::new([\"http\", \"https\"], [80, 443]);\n"
+    /// );
+    /// ```
+    pub fn generate(&self, format: Format, input: &str) -> Result<String, Error> {
+        let entries = match format {
+            Format::Csv => read_csv(input, self.csv_headers)?,
+            Format::Json => read_json(input)?,
+            Format::Toml => read_toml(input)?,
+        };
+        self.render(entries)
+    }
+
+    fn render(&self, entries: Vec<(String, Value)>) -> Result<String, Error> {
+        // cargo sets this for build scripts
+        let pointer_width = self
+            .pointer_width
+            .or_else(|| {
+                std::env::var("CARGO_CFG_TARGET_POINTER_WIDTH")
+                    .ok()?
+                    .parse()
+                    .ok()
+            })
+            .unwrap_or(16);
+        let value_type = match &self.value_type {
+            Some(value_type) => value_type.clone(),
+            None => infer_value_type(&entries)?,
+        };
+
+        let mut sorted = BTreeMap::new();
+        for (key, value) in entries {
+            let parsed_key = parse_key(&key, &self.key_type, pointer_width)?;
+            let value = convert_value(&key, value, &value_type, pointer_width)?;
+            match sorted.entry(parsed_key) {
+                Entry::Vacant(entry) => {
+                    entry.insert(value);
+                }
+                Entry::Occupied(entry) if *entry.get() == value => {}
+                Entry::Occupied(_) => return Err(Error::DuplicateKey(key)),
+            }
+        }
+
+        let mut keys = String::new();
+        let mut values = String::new();
+        for (index, (key, value)) in sorted.iter().enumerate() {
+            let separator = if index == 0 { "" } else { ", " };
+            match key {
+                Key::Str(key) => write!(keys, "{separator}{key:?}"),
+                Key::Int(key) => write!(keys, "{separator}{key}"),
+            }
+            .expect("writing to a string cannot fail");
+            write!(values, "{separator}{value}").expect("writing to a string cannot fail");
+        }
+
+        let visibility = if self.visibility.is_empty() {
+            String::new()
+        } else {
+            format!("{} ", self.visibility)
+        };
+        Ok(format!(
+            "{visibility}const {}: ::const_lookup_map::ConstLookup<{}, {}, {value_type}> = \
+             ::const_lookup_map::ConstLookup::new([{keys}], [{values}]);\n",
+            self.name,
+            sorted.len(),
+            self.key_type,
+        ))
+    }
+}
+
+fn integer_range(ty: &str, pointer_width: u32) -> Option<(Int, Int)> {
+    match ty {
+        "usize" => return Some((Int::new(0), Int::new((1 << pointer_width) - 1))),
+        "isize" => {
+            let max = (1 << (pointer_width - 1)) - 1;
+            return Some((Int::new(-max - 1), Int::new(max)));
+        }
+        _ => {}
+    }
+    INTEGER_TYPES
+        .iter()
+        .find(|(name, _, _)| *name == ty)
+        .map(|(_, min, max)| (*min, *max))
+}
+
+fn parse_key(key: &str, key_type: &str, pointer_width: u32) -> Result<Key, Error> {
+    if key_type == "&str" || key_type == "&'static str" {
+        return Ok(Key::Str(key.to_string()));
+    }
+    let (min, max) = integer_range(key_type, pointer_width)
+        .ok_or_else(|| Error::UnsupportedKeyType(key_type.to_string()))?;
+    match Int::parse(key) {
+        Some(parsed) if (min..=max).contains(&parsed) => Ok(Key::Int(parsed)),
+        _ => Err(Error::InvalidKey {
+            key: key.to_string(),
+            key_type: key_type.to_string(),
+        }),
+    }
+}
+
+fn infer_value_type(entries: &[(String, Value)]) -> Result<String, Error> {
+    let mut value_type = "&str";
+    // the smallest integer, and the largest with its key
+    let mut min: Option<Int> = None;
+    let mut max: Option<(Int, &str)> = None;
+    for (index, (key, value)) in entries.iter().enumerate() {
+        if let Value::Int(int) = *value {
+            min = Some(min.map_or(int, |min| min.min(int)));
+            max = match max {
+                Some(largest) if largest.0 >= int => Some(largest),
+                _ => Some((int, key)),
+            };
+        }
+        let current = value.type_name();
+        if index == 0 || (value_type, current) == ("integer", "f64") {
+            value_type = current;
+        } else if value_type != current && (value_type, current) != ("f64", "integer") {
+            return Err(Error::MixedValueTypes { key: key.clone() });
+        }
+    }
+
+    match (value_type, min, max) {
+        ("integer", Some(min), Some((max, max_key))) => ["i64", "u64", "i128", "u128"]
+            .into_iter()
+            .find(|ty| {
+                let (ty_min, ty_max) = integer_range(ty, 64).expect("built in integer type");
+                ty_min <= min && max <= ty_max
+            })
+            .map(str::to_string)
+            // a negative value and one above `i128::MAX`, which no integer type can hold both of
+            .ok_or_else(|| Error::UnsupportedValue {
+                key: max_key.to_string(),
+                value: max.to_string(),
+            }),
+        _ => Ok(value_type.to_string()),
+    }
+}
+
+fn convert_value(
+    key: &str,
+    value: Value,
+    value_type: &str,
+    pointer_width: u32,
+) -> Result<Value, Error> {
+    let unsupported = |value: &Value| Error::UnsupportedValue {
+        key: key.to_string(),
+        value: value.to_string(),
+    };
+
+    let converted = match (value_type, &value) {
+        ("&str" | "&'static str", Value::Str(_)) => Some(value.clone()),
+        ("bool", Value::Bool(_)) => Some(value.clone()),
+        ("bool", Value::Str(text)) => text.trim().parse().ok().map(Value::Bool),
+        ("f32" | "f64", Value::Float(float)) => Some(Value::Float(*float)),
+        ("f32" | "f64", Value::Int(int)) => Some(Value::Float(int.to_f64())),
+        ("f32" | "f64", Value::Str(text)) => text.trim().parse().ok().map(Value::Float),
+        ("&str" | "&'static str" | "bool" | "f32" | "f64", _) => None,
+        (integer, _) => {
+            let (min, max) = integer_range(integer, pointer_width)
+                .ok_or_else(|| Error::UnsupportedValueType(integer.to_string()))?;
+            let parsed = match &value {
+                Value::Int(int) => Some(*int),
+                Value::Str(text) => Int::parse(text),
+                _ => None,
+            };
+            parsed
+                .filter(|int| (min..=max).contains(int))
+                .map(Value::Int)
+        }
+    };
+
+    match converted {
+        Some(Value::Float(float)) if !float.is_finite() => Err(unsupported(&value)),
+        // the literal would not compile
+        Some(Value::Float(float)) if value_type == "f32" && float.abs() > f32::MAX as f64 => {
+            Err(unsupported(&value))
+        }
+        Some(converted) => Ok(converted),
+        None => Err(unsupported(&value)),
+    }
+}
+
+fn read_csv(input: &str, headers: bool) -> Result<Vec<(String, Value)>, Error> {
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(headers)
+        .from_reader(input.as_bytes());
+
+    let mut entries = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|error| Error::Parse(error.to_string()))?;
+        match (record.get(0), record.get(1)) {
+            (Some(key), Some(value)) => {
+                entries.push((key.to_string(), Value::Str(value.to_string())))
+            }
+            _ => {
+                let line = record.position().map_or(0, |position| position.line());
+                return Err(Error::Parse(format!(
+                    "expected a key and a value on line {line}"
+                )));
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// The entries of a JSON object in order, serde_json would silently drop duplicate keys.
+struct JsonEntries(Vec<(String, serde_json::Value)>);
+
+impl<'de> serde::Deserialize<'de> for JsonEntries {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = JsonEntries;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an object")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<JsonEntries, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(JsonEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(Visitor)
+    }
+}
+
+fn read_json(input: &str) -> Result<Vec<(String, Value)>, Error> {
+    let JsonEntries(entries) =
+        serde_json::from_str(input).map_err(|error| Error::Parse(error.to_string()))?;
+
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                serde_json::Value::String(value) => Value::Str(value),
+                serde_json::Value::Bool(value) => Value::Bool(value),
+                serde_json::Value::Number(ref number) => {
+                    match (number.as_i64(), number.as_u64(), number.as_f64()) {
+                        (Some(int), _, _) => Value::Int(Int::new(int.into())),
+                        (_, Some(int), _) => Value::Int(Int::new(int.into())),
+                        (_, _, Some(float)) => Value::Float(float),
+                        _ => return Err(unsupported_json(key, &value)),
+                    }
+                }
+                _ => return Err(unsupported_json(key, &value)),
+            };
+            Ok((key, value))
+        })
+        .collect()
+}
+
+fn unsupported_json(key: String, value: &serde_json::Value) -> Error {
+    Error::UnsupportedValue {
+        key,
+        value: value.to_string(),
+    }
+}
+
+fn read_toml(input: &str) -> Result<Vec<(String, Value)>, Error> {
+    let table: toml::Table = input
+        .parse()
+        .map_err(|error: toml::de::Error| Error::Parse(error.to_string()))?;
+
+    table
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                toml::Value::String(value) => Value::Str(value),
+                toml::Value::Integer(value) => Value::Int(Int::new(value.into())),
+                toml::Value::Float(value) => Value::Float(value),
+                toml::Value::Boolean(value) => Value::Bool(value),
+                value => {
+                    return Err(Error::UnsupportedValue {
+                        key,
+                        value: value.to_string(),
+                    })
+                }
+            };
+            Ok((key, value))
+        })
+        .collect()
+}
+
+#[test]
+fn codegen_csv_test() {
+    let source = Generator::new("STATUS")
+        .visibility("pub(crate)")
+        .key_type("u16")
+        .generate(
+            Format::Csv,
+            "code,name\n404,Not Found\n200,OK\n500,\"Internal, Server Error\"\n200,OK\n",
+        )
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "pub(crate) const STATUS: ::const_lookup_map::ConstLookup<3, u16, &str> = \
+         ::const_lookup_map::ConstLookup::new([200, 404, 500], \
+         [\"OK\", \"Not Found\", \"Internal, Server Error\"]);\n"
+    );
+}
+
+#[test]
+fn codegen_csv_value_type_test() {
+    let source = Generator::new("LIMITS")
+        .visibility("")
+        .value_type("u8")
+        .csv_headers(false)
+        .generate(Format::Csv, "b,2\na,1\n")
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "const LIMITS: ::const_lookup_map::ConstLookup<2, &str, u8> = \
+         ::const_lookup_map::ConstLookup::new([\"a\", \"b\"], [1, 2]);\n"
+    );
+}
+
+#[test]
+fn codegen_full_integer_range_test() {
+    let source = Generator::new("IDS")
+        .key_type("u128")
+        .value_type("i128")
+        .generate(
+            Format::Csv,
+            "id,value\n340282366920938463463374607431768211455,-170141183460469231731687303715884105728\n0,-0\n",
+        )
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "pub const IDS: ::const_lookup_map::ConstLookup<2, u128, i128> = \
+         ::const_lookup_map::ConstLookup::new([0, 340282366920938463463374607431768211455], \
+         [0, -170141183460469231731687303715884105728]);\n"
+    );
+    assert!(matches!(
+        Generator::new("IDS").key_type("i128").generate(Format::Csv, "k,v\n-+1,x\n"),
+        Err(Error::InvalidKey { key, .. }) if key == "-+1"
+    ));
+}
+
+#[test]
+fn codegen_json_test() {
+    let source = Generator::new("RATES")
+        .generate(Format::Json, r#"{"b": 1, "a": 0.5, "c\"": 2}"#)
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "pub const RATES: ::const_lookup_map::ConstLookup<3, &str, f64> = \
+         ::const_lookup_map::ConstLookup::new([\"a\", \"b\", \"c\\\"\"], [0.5, 1.0, 2.0]);\n"
+    );
+}
+
+#[test]
+fn codegen_pointer_width_test() {
+    let generator = Generator::new("OFFSETS")
+        .key_type("usize")
+        .value_type("isize");
+
+    assert!(matches!(
+        generator.clone().pointer_width(16).generate(Format::Csv, "k,v\n65536,0\n"),
+        Err(Error::InvalidKey { key, .. }) if key == "65536"
+    ));
+    assert!(matches!(
+        generator.clone().pointer_width(16).generate(Format::Csv, "k,v\n0,-32769\n"),
+        Err(Error::UnsupportedValue { key, .. }) if key == "0"
+    ));
+    assert!(generator
+        .pointer_width(32)
+        .generate(Format::Csv, "k,v\n4294967295,-2147483648\n")
+        .is_ok());
+}
+
+#[test]
+fn codegen_infers_wide_integers_test() {
+    let generator = Generator::new("SIZES");
+    let inferred = |input| {
+        let source = generator.generate(Format::Json, input).unwrap();
+        source.split(['<', '>']).nth(1).unwrap().to_string()
+    };
+
+    assert_eq!(inferred(r#"{"a": -1, "b": 1}"#), "2, &str, i64");
+    assert_eq!(inferred(r#"{"a": 18446744073709551615}"#), "1, &str, u64");
+    assert_eq!(
+        inferred(r#"{"a": -1, "b": 18446744073709551615}"#),
+        "2, &str, i128"
+    );
+    assert_eq!(inferred(r#"{"a": 1, "b": 0.5}"#), "2, &str, f64");
+}
+
+#[test]
+fn codegen_toml_test() {
+    let source = Generator::new("FLAGS")
+        .generate(Format::Toml, "verbose = true\ncolor = false\n")
+        .unwrap();
+
+    assert_eq!(
+        source,
+        "pub const FLAGS: ::const_lookup_map::ConstLookup<2, &str, bool> = \
+         ::const_lookup_map::ConstLookup::new([\"color\", \"verbose\"], [false, true]);\n"
+    );
+}
+
+#[test]
+fn codegen_errors_test() {
+    let generator = Generator::new("ERRORS");
+
+    assert!(matches!(
+        generator.generate(Format::Json, r#"{"a": 1, "a": 2}"#),
+        Err(Error::DuplicateKey(key)) if key == "a"
+    ));
+    assert!(matches!(
+        generator.generate(Format::Json, r#"{"a": [1]}"#),
+        Err(Error::UnsupportedValue { key, .. }) if key == "a"
+    ));
+    assert!(matches!(
+        generator.generate(Format::Json, r#"{"a": 1, "b": "two"}"#),
+        Err(Error::MixedValueTypes { key }) if key == "b"
+    ));
+    assert!(matches!(
+        generator.clone().value_type("f32").generate(Format::Json, r#"{"a": 1e300}"#),
+        Err(Error::UnsupportedValue { key, .. }) if key == "a"
+    ));
+    assert!(matches!(
+        generator.generate(Format::Toml, "a = 1\na = 2\n"),
+        Err(Error::Parse(_))
+    ));
+    assert!(matches!(
+        generator.clone().key_type("u8").generate(Format::Csv, "k,v\n256,x\n"),
+        Err(Error::InvalidKey { key, .. }) if key == "256"
+    ));
+    assert!(matches!(
+        generator.value_type("Vec<u8>").generate(Format::Csv, "k,v\na,x\n"),
+        Err(Error::UnsupportedValueType(ty)) if ty == "Vec<u8>"
+    ));
+    assert!(matches!(
+        Generator::new("ERRORS").key_type("bool").generate(Format::Csv, "k,v\ntrue,x\n"),
+        Err(Error::UnsupportedKeyType(ty)) if ty == "bool"
+    ));
+    assert_eq!(
+        Error::UnsupportedKeyType(String::from("bool")).to_string(),
+        "key type `bool` is not supported, use `&str` or an integer type"
+    );
+}
@@ -0,0 +1,222 @@
+use crate::key::{self, ConstKey};
+use crate::ConstLookup;
+
+/// Map with integer keys that can be defined in a const context, indexed directly when the keys are contiguous.
+///
+/// When the keys are a range like `0..N`, or any other range without gaps, [`get`](ConstDenseLookup::get) subtracts
+/// the first key from the key and uses the result as the position of the value, without searching. When the keys
+/// have gaps it falls back to the binary search of [`ConstLookup`].
+///
+/// ```rust
+/// use const_lookup_map::{ConstDenseLookup, dense_lookup};
+///
+/// const LEVELS: ConstDenseLookup<3, u8, &str> = dense_lookup! {
+///     2 => "error",
+///     0 => "info",
+///     1 => "warning",
+/// };
+///
+/// assert!(LEVELS.is_dense());
+/// assert_eq!(LEVELS.get(&1), Some(&"warning"));
+/// assert_eq!(LEVELS.get(&3), None);
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstDenseLookup<const N: usize, K: Ord, V> {
+    lookup: ConstLookup<N, K, V>,
+    /// The ordinal of the first key, if the keys are contiguous.
+    base: Option<u128>,
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> ConstDenseLookup<N, K, V> {
+    /// Creates the map from a sorted lookup and checks if the keys are contiguous, this can be used in a const
+    /// context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are not integers, are out of order or contain duplicates.
+    pub const fn new(lookup: ConstLookup<N, K, V>) -> ConstDenseLookup<N, K, V> {
+        assert!(
+            key::is_integer::<K>(),
+            "keys of ConstDenseLookup must be integers"
+        );
+        let lookup = lookup.checked();
+        let base = if N == 0 {
+            None
+        } else {
+            let first = key::ordinal(&lookup.keys[0]);
+            // the keys are strictly sorted, so they are contiguous if the last key is `N - 1` after the first
+            if key::ordinal(&lookup.keys[N - 1]) - first == N as u128 - 1 {
+                Some(first)
+            } else {
+                None
+            }
+        };
+        ConstDenseLookup { lookup, base }
+    }
+
+    /// Returns the number of elements in the map.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns true if the keys are contiguous, so lookups do not have to search.
+    pub const fn is_dense(&self) -> bool {
+        self.base.is_some()
+    }
+
+    /// Returns the map that is searched when the keys are not contiguous, to use the rest of the [`ConstLookup`]
+    /// methods.
+    pub const fn lookup(&self) -> &ConstLookup<N, K, V> {
+        &self.lookup
+    }
+
+    /// Returns the position of the key in the map.
+    pub fn get_index_of(&self, key: &K) -> Option<usize> {
+        match self.base {
+            Some(base) => {
+                let index = key::ordinal(key).wrapping_sub(base);
+                if index < N as u128 {
+                    Some(index as usize)
+                } else {
+                    None
+                }
+            }
+            None => self.lookup.get_index_of(key),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let index = self.get_index_of(key)?;
+        self.lookup.values.get(index)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_index_of(key).is_some()
+    }
+}
+
+impl<const N: usize, K: Ord + ConstKey + Copy, V> ConstDenseLookup<N, K, V> {
+    /// Creates the map with the keys `offset`, `offset + 1`, and so on, this can be used in a const context.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstDenseLookup, dense_lookup};
+    ///
+    /// const MONTHS: ConstDenseLookup<3, u8, &str> = dense_lookup! {
+    ///     offset: 1;
+    ///     "January",
+    ///     "February",
+    ///     "March",
+    /// };
+    ///
+    /// assert_eq!(MONTHS.get(&2), Some(&"February"));
+    /// assert_eq!(MONTHS.get(&0), None);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are not integers or if the last key does not fit in the key type.
+    pub const fn with_offset(offset: K, values: [V; N]) -> ConstDenseLookup<N, K, V> {
+        assert!(
+            key::is_integer::<K>(),
+            "keys of ConstDenseLookup must be integers"
+        );
+        let base = key::ordinal(&offset);
+        let mut keys = [offset; N];
+        let mut i = 1;
+        while i < N {
+            let ordinal = match base.checked_add(i as u128) {
+                Some(ordinal) => ordinal,
+                None => panic!("integer does not fit in the key type"),
+            };
+            keys[i] = key::from_ordinal(ordinal);
+            i += 1;
+        }
+        ConstDenseLookup {
+            lookup: ConstLookup::new(keys, values),
+            base: if N == 0 { None } else { Some(base) },
+        }
+    }
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> core::ops::Index<&K> for ConstDenseLookup<N, K, V> {
+    type Output = V;
+
+    fn index(&self, index: &K) -> &V {
+        self.get(index)
+            .expect("key not found in ConstDenseLookup, use `get` for a safe option")
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[test]
+fn dense_lookup_indexes_contiguous_keys() {
+    const COLORS: ConstDenseLookup<3, i8, Color> = crate::dense_lookup! {
+        -1 => Color::Red,
+        1 => Color::Blue,
+        0 => Color::Green,
+    };
+
+    assert!(COLORS.is_dense());
+    assert_eq!(COLORS.get(&-1), Some(&Color::Red));
+    assert_eq!(COLORS[&1], Color::Blue);
+    assert_eq!(COLORS.get(&-2), None);
+    assert_eq!(COLORS.get(&2), None);
+    assert_eq!(COLORS.get(&i8::MIN), None);
+    assert_eq!(COLORS.get_index_of(&0), Some(1));
+}
+
+#[test]
+fn dense_lookup_with_offset() {
+    const CODES: ConstDenseLookup<3, u16, Color> = crate::dense_lookup! {
+        offset: 400;
+        Color::Red,
+        Color::Green,
+        Color::Blue,
+    };
+    const EMPTY: ConstDenseLookup<0, u16, Color> = crate::dense_lookup! { offset: 0; };
+
+    assert_eq!(CODES.lookup().keys, [400, 401, 402]);
+    assert_eq!(CODES.get(&402), Some(&Color::Blue));
+    assert!(!CODES.contains_key(&403));
+    assert!(!EMPTY.contains_key(&0));
+    assert!(!EMPTY.is_dense());
+}
+
+#[test]
+fn dense_lookup_falls_back_for_gaps() {
+    const GAPS: ConstDenseLookup<3, u32, u8> = crate::dense_lookup! {
+        1 => 1,
+        2 => 2,
+        4 => 4,
+    };
+
+    assert!(!GAPS.is_dense());
+    assert_eq!(GAPS.get(&4), Some(&4));
+    assert_eq!(GAPS.get(&3), None);
+}
+
+#[test]
+#[should_panic(expected = "integer does not fit in the key type")]
+fn dense_lookup_rejects_overflowing_offset() {
+    let _: ConstDenseLookup<3, u8, u8> = ConstDenseLookup::with_offset(254, [0; 3]);
+}
+
+#[test]
+#[should_panic(expected = "integer does not fit in the key type")]
+fn dense_lookup_rejects_overflowing_ordinal() {
+    let _: ConstDenseLookup<2, i128, u8> = ConstDenseLookup::with_offset(i128::MAX, [0; 2]);
+}
@@ -0,0 +1,290 @@
+use core::borrow::Borrow;
+use core::mem::{self, size_of};
+use core::ptr;
+
+use crate::key::{self, ConstKey};
+use crate::{iter, ConstLookup};
+
+/// Map that can be defined in a const context, with the keys stored in Eytzinger order.
+///
+/// The sorted keys are arranged like a complete binary search tree stored breadth first: the root first, then its two
+/// children, then their four children, and so on. The first steps of every search touch the same few cache lines, the
+/// search loop has no branches to mispredict and for large tables it prefetches the keys a few levels down. This can
+/// help for large tables, but not always: with 100k entries in `benches/lookup.rs` it is faster than the binary search
+/// of [`ConstLookup`] for tuple keys, about as fast for `&str` keys and slower for `u32` keys.
+/// Iterating still returns the entries in key order.
+///
+/// ```rust
+/// use const_lookup_map::{ConstEytzingerLookup, eytzinger_lookup};
+///
+/// const LOOKUP: ConstEytzingerLookup<4, &str, u8> = eytzinger_lookup! {
+///     "d" => 4,
+///     "b" => 2,
+///     "a" => 1,
+///     "c" => 3,
+/// };
+///
+/// assert_eq!(LOOKUP.keys, ["c", "b", "d", "a"]);
+/// assert_eq!(LOOKUP.get("b"), Some(&2));
+/// assert!(LOOKUP.iter().map(|(key, _)| *key).eq(["a", "b", "c", "d"]));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstEytzingerLookup<const N: usize, K: Ord, V> {
+    pub keys: [K; N],
+    pub values: [V; N],
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> ConstEytzingerLookup<N, K, V> {
+    /// Creates the map from a sorted lookup, this can be used in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are out of order or contain duplicates.
+    pub const fn new(lookup: ConstLookup<N, K, V>) -> ConstEytzingerLookup<N, K, V> {
+        let lookup = lookup.checked();
+        // SAFETY: the lookup is forgotten right after, so the keys and values are only moved out once.
+        let mut keys = unsafe { ptr::read(&lookup.keys) };
+        let mut values = unsafe { ptr::read(&lookup.values) };
+        mem::forget(lookup);
+
+        // `order[position]` is the sorted index of the entry at that position in the tree
+        let mut order = [0usize; N];
+        let mut node = first(N);
+        let mut i = 0;
+        while i < N {
+            order[node - 1] = i;
+            node = successor(node, N);
+            i += 1;
+        }
+        key::permute(&mut keys, &mut values, &order);
+
+        ConstEytzingerLookup { keys, values }
+    }
+}
+
+impl<const N: usize, K: Ord, V> ConstEytzingerLookup<N, K, V> {
+    /// Number of nodes between a node and its first descendant a few levels down, about a cache line of keys.
+    const PREFETCH_DISTANCE: usize = {
+        let size = if size_of::<K>() == 0 {
+            1
+        } else {
+            size_of::<K>()
+        };
+        if size >= 64 {
+            1
+        } else {
+            64 / size
+        }
+    };
+
+    /// Small tables stay in the cache anyway, prefetching only costs time there.
+    const PREFETCH: bool = N * size_of::<K>() > 64 * 1024;
+
+    /// Returns the number of elements in the map.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    fn search<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        // walk down to a leaf, going right when the node is smaller than the key
+        let mut node = 1;
+        while node <= N {
+            if Self::PREFETCH {
+                prefetch(
+                    self.keys
+                        .as_ptr()
+                        .wrapping_add(node * Self::PREFETCH_DISTANCE),
+                );
+            }
+            // SAFETY: `node` is between 1 and `N`, checked by the loop condition.
+            let probe = unsafe { self.keys.get_unchecked(node - 1) };
+            node = 2 * node + usize::from(probe.borrow() < key);
+        }
+        // the last node where the walk went left is the smallest key that is not smaller
+        node >>= node.trailing_ones() + 1;
+        if node != 0 && self.keys[node - 1].borrow() == key {
+            Some(node - 1)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering on the borrowed form must match the
+    /// ordering on the key type.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let index = self.search(key)?;
+        self.values.get(index)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.search(key).is_some()
+    }
+
+    /// Returns an iterator over the entries of the map, in key order.
+    pub fn iter(&self) -> iter::EytzingerIter<'_, K, V> {
+        iter::EytzingerIter::new(&self.keys, &self.values)
+    }
+}
+
+/// Asks the CPU to load the cache line of `pointer`, which does nothing for an address that is not mapped.
+#[inline(always)]
+fn prefetch<T>(pointer: *const T) {
+    #[cfg(target_arch = "x86_64")]
+    // SAFETY: prefetching is only a hint and never dereferences the pointer.
+    unsafe {
+        core::arch::x86_64::_mm_prefetch::<{ core::arch::x86_64::_MM_HINT_T0 }>(pointer.cast());
+    }
+    #[cfg(not(target_arch = "x86_64"))]
+    let _ = pointer;
+}
+
+/// Returns the node with the smallest key of a tree with `len` nodes, nodes are numbered from 1.
+pub(crate) const fn first(len: usize) -> usize {
+    let mut node = 1;
+    while 2 * node <= len {
+        node *= 2;
+    }
+    node
+}
+
+/// Returns the node with the largest key of a tree with `len` nodes.
+pub(crate) const fn last(len: usize) -> usize {
+    let mut node = 1;
+    while 2 * node < len {
+        node = 2 * node + 1;
+    }
+    node
+}
+
+/// Returns the node with the next key, or 0 after the last node.
+pub(crate) const fn successor(mut node: usize, len: usize) -> usize {
+    if 2 * node < len {
+        node = 2 * node + 1;
+        while 2 * node <= len {
+            node *= 2;
+        }
+        node
+    } else {
+        node >> (node.trailing_ones() + 1)
+    }
+}
+
+/// Returns the node with the previous key, or 0 before the first node.
+pub(crate) const fn predecessor(mut node: usize, len: usize) -> usize {
+    if 2 * node <= len {
+        node *= 2;
+        while 2 * node < len {
+            node = 2 * node + 1;
+        }
+        node
+    } else {
+        node >> (node.trailing_zeros() + 1)
+    }
+}
+
+impl<const N: usize, K, V, Q> core::ops::Index<&Q> for ConstEytzingerLookup<N, K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: ?Sized + Ord,
+{
+    type Output = V;
+
+    fn index(&self, index: &Q) -> &V {
+        self.get(index)
+            .expect("key not found in ConstEytzingerLookup, use `get` for a safe option")
+    }
+}
+
+impl<'a, const N: usize, K: Ord, V> IntoIterator for &'a ConstEytzingerLookup<N, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = iter::EytzingerIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+const fn squares<const N: usize>() -> ConstLookup<N, u32, usize> {
+    let mut keys = [0; N];
+    let mut values = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = (i * i) as u32;
+        values[i] = i;
+        i += 1;
+    }
+    ConstLookup::new(keys, values)
+}
+
+#[test]
+fn eytzinger_lookup_finds_every_key() {
+    fn check<const N: usize>(lookup: ConstEytzingerLookup<N, u32, usize>) {
+        for i in 0..N {
+            let key = (i * i) as u32;
+            assert_eq!(lookup.get(&key), Some(&i));
+            assert_eq!(
+                lookup.get(&(key + 1)),
+                if i == 0 && N > 1 { Some(&1) } else { None }
+            );
+        }
+        assert!(lookup.iter().map(|(_, value)| *value).eq(0..N));
+        assert!(lookup
+            .iter()
+            .rev()
+            .map(|(_, value)| *value)
+            .eq((0..N).rev()));
+    }
+
+    check(ConstEytzingerLookup::new(squares::<0>()));
+    check(ConstEytzingerLookup::new(squares::<1>()));
+    check(ConstEytzingerLookup::new(squares::<2>()));
+    check(ConstEytzingerLookup::new(squares::<7>()));
+    check(ConstEytzingerLookup::new(squares::<8>()));
+    check(ConstEytzingerLookup::new(squares::<100>()));
+}
+
+#[test]
+fn eytzinger_lookup_iter_test() {
+    const LOOKUP: ConstEytzingerLookup<6, char, u8> = crate::eytzinger_lookup! {
+        'f' => 6,
+        'a' => 1,
+        'e' => 5,
+        'b' => 2,
+        'd' => 4,
+        'c' => 3,
+    };
+
+    let mut iter = LOOKUP.iter();
+
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some((&'a', &1)));
+    assert_eq!(iter.next_back(), Some((&'f', &6)));
+    assert_eq!(iter.next(), Some((&'b', &2)));
+    assert_eq!(iter.next_back(), Some((&'e', &5)));
+    assert_eq!(iter.len(), 2);
+    assert!(iter.map(|(key, _)| *key).eq(['c', 'd']));
+    assert!((&LOOKUP).into_iter().eq(LOOKUP.iter()));
+    assert_eq!(LOOKUP[&'c'], 3);
+    assert!(!LOOKUP.contains_key(&'g'));
+}
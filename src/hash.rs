@@ -0,0 +1,346 @@
+use core::borrow::Borrow;
+
+use crate::key::{self, ConstKey, KeyHash};
+
+/// Number of seeds that are tried before giving up on finding a perfect hash.
+const MAX_SEEDS: u64 = 64;
+
+type Displacements<const N: usize> = [(u32, u32); N];
+
+/// Map that can be defined in a const context, backed by a perfect hash function.
+///
+/// The perfect hash is found while creating the map, so a lookup hashes the key once and does a single comparison
+/// instead of the `O(log N)` comparisons of [`ConstLookup`](crate::ConstLookup). This uses the
+/// "hash, displace and compress" (CHD) algorithm, the keys are stored in hash order and not in sorted order.
+///
+/// ```rust
+/// use const_lookup_map::{ConstHashLookup, hash_lookup};
+///
+/// const LOOKUP: ConstHashLookup<3, &str, &str> = hash_lookup! {
+///     "best" => "better",
+///     "test" => "testing",
+///     "guessed" => "guessing",
+/// };
+///
+/// assert_eq!(LOOKUP.get(&"guessed"), Some(&"guessing"));
+/// assert_eq!(LOOKUP[&"best"], "better");
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstHashLookup<const N: usize, K, V> {
+    pub keys: [K; N],
+    pub values: [V; N],
+    seed: u64,
+    displacements: Displacements<N>,
+}
+
+/// Returns the bucket of the hash and the two hashes that are combined with the displacement of that bucket.
+const fn hashes(first: u64, len: usize) -> (usize, u32, u32) {
+    let second = key::rehash(first);
+    (((first >> 32) as usize) % len, first as u32, second as u32)
+}
+
+const fn slot((_, f1, f2): (usize, u32, u32), (d1, d2): (u32, u32), len: usize) -> usize {
+    (d2.wrapping_add(f1.wrapping_mul(d1)).wrapping_add(f2) as usize) % len
+}
+
+/// Tries to find a displacement for every bucket with this seed.
+///
+/// Returns the displacements and, for every slot, the index of the entry that belongs there.
+const fn build<const N: usize, K: ConstKey>(
+    keys: &[K; N],
+    seed: u64,
+) -> Option<(Displacements<N>, [usize; N])> {
+    let mut hashes_of = [(0usize, 0u32, 0u32); N];
+    let mut sizes = [0usize; N];
+    let mut i = 0;
+    while i < N {
+        hashes_of[i] = hashes(key::hash(&keys[i], seed), N);
+        sizes[hashes_of[i].0] += 1;
+        i += 1;
+    }
+
+    // group the entries by bucket, bucket `b` is `members[starts[b]..starts[b] + sizes[b]]`
+    let mut starts = [0usize; N];
+    let mut largest = 0;
+    let mut total = 0;
+    let mut bucket = 0;
+    while bucket < N {
+        starts[bucket] = total;
+        total += sizes[bucket];
+        if sizes[bucket] > largest {
+            largest = sizes[bucket];
+        }
+        bucket += 1;
+    }
+
+    let mut members = [0usize; N];
+    let mut filled = [0usize; N];
+    let mut i = 0;
+    while i < N {
+        let bucket = hashes_of[i].0;
+        members[starts[bucket] + filled[bucket]] = i;
+        filled[bucket] += 1;
+        i += 1;
+    }
+
+    // place the largest buckets first, while most slots are still free
+    let mut displacements: Displacements<N> = [(0, 0); N];
+    let mut order = [0usize; N];
+    let mut taken = [false; N];
+    let mut size = largest;
+    while size > 0 {
+        let mut bucket = 0;
+        while bucket < N {
+            if sizes[bucket] != size {
+                bucket += 1;
+                continue;
+            }
+            let members = members.split_at(starts[bucket]).1.split_at(size).0;
+
+            let mut a = 0;
+            while a < size {
+                let mut b = a + 1;
+                while b < size {
+                    assert!(
+                        !key::cmp(&keys[members[a]], &keys[members[b]]).is_eq(),
+                        "duplicate key in ConstHashLookup"
+                    );
+                    b += 1;
+                }
+                a += 1;
+            }
+
+            let mut placed = false;
+            let mut d1 = 0;
+            'search: while d1 < N as u32 {
+                let mut d2 = 0;
+                'displacement: while d2 < N as u32 {
+                    let mut a = 0;
+                    while a < size {
+                        let index = slot(hashes_of[members[a]], (d1, d2), N);
+                        if taken[index] {
+                            d2 += 1;
+                            continue 'displacement;
+                        }
+                        let mut b = 0;
+                        while b < a {
+                            if slot(hashes_of[members[b]], (d1, d2), N) == index {
+                                d2 += 1;
+                                continue 'displacement;
+                            }
+                            b += 1;
+                        }
+                        a += 1;
+                    }
+
+                    let mut a = 0;
+                    while a < size {
+                        let index = slot(hashes_of[members[a]], (d1, d2), N);
+                        taken[index] = true;
+                        order[index] = members[a];
+                        a += 1;
+                    }
+                    displacements[bucket] = (d1, d2);
+                    placed = true;
+                    break 'search;
+                }
+                d1 += 1;
+            }
+
+            if !placed {
+                return None;
+            }
+            bucket += 1;
+        }
+        size -= 1;
+    }
+
+    Some((displacements, order))
+}
+
+impl<const N: usize, K: Eq + ConstKey, V> ConstHashLookup<N, K, V> {
+    /// Returns the number of elements in the map.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Creates the map, this finds the perfect hash function for the keys so can be used in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys contain duplicates.
+    pub const fn new(mut keys: [K; N], mut values: [V; N]) -> ConstHashLookup<N, K, V> {
+        let mut attempt = 0;
+        loop {
+            let seed = key::rehash(attempt);
+            if let Some((displacements, order)) = build(&keys, seed) {
+                key::permute(&mut keys, &mut values, &order);
+                return ConstHashLookup {
+                    keys,
+                    values,
+                    seed,
+                    displacements,
+                };
+            }
+            attempt += 1;
+            assert!(
+                attempt < MAX_SEEDS,
+                "could not find a perfect hash function for the keys of ConstHashLookup"
+            );
+        }
+    }
+
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + KeyHash,
+    {
+        if N == 0 {
+            return None;
+        }
+        let hashes = hashes(key.key_hash(self.seed), N);
+        let index = slot(hashes, self.displacements[hashes.0], N);
+        (self.keys[index].borrow() == key).then_some(index)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// The key may be any borrowed form of the map's key type, like `str` for `&str` keys.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + KeyHash,
+    {
+        let index = self.index_of(key)?;
+        self.values.get(index)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    ///
+    /// The key may be any borrowed form of the map's key type, like `str` for `&str` keys.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Eq + KeyHash,
+    {
+        self.index_of(key).is_some()
+    }
+}
+
+impl<const N: usize, K, V, Q> core::ops::Index<&Q> for ConstHashLookup<N, K, V>
+where
+    K: Eq + ConstKey + Borrow<Q>,
+    Q: ?Sized + Eq + KeyHash,
+{
+    type Output = V;
+
+    fn index(&self, index: &Q) -> &V {
+        self.get(index)
+            .expect("key not found in ConstHashLookup, use `get` for a safe option")
+    }
+}
+
+#[cfg(test)]
+const HASH_LOOKUP: ConstHashLookup<4, &str, &str> = crate::hash_lookup! {
+    "bye" => "bye.example.com",
+    "hallo" => "hallo.example.com",
+    "hey" => "hey.example.com",
+    "test" => "test.example.com",
+};
+
+#[cfg(test)]
+const fn numbers<const N: usize>(step: u32) -> [u32; N] {
+    let mut numbers = [0; N];
+    let mut i = 0;
+    while i < N {
+        numbers[i] = i as u32 * step;
+        i += 1;
+    }
+    numbers
+}
+
+#[test]
+fn hash_lookup_get_test() {
+    assert_eq!(HASH_LOOKUP.get(&"hey"), Some(&"hey.example.com"));
+    assert_eq!(HASH_LOOKUP.get(&"hello"), None);
+    assert!(HASH_LOOKUP.contains_key(&"bye"));
+    assert!(!HASH_LOOKUP.contains_key(&"by"));
+}
+
+#[test]
+fn hash_lookup_borrowed_key_test() {
+    use std::string::String;
+
+    let key = String::from("test");
+
+    assert_eq!(HASH_LOOKUP.get(key.as_str()), Some(&"test.example.com"));
+    assert!(HASH_LOOKUP.contains_key("bye"));
+    assert!(!HASH_LOOKUP.contains_key("hello"));
+    assert_eq!(HASH_LOOKUP[key.as_str()], "test.example.com");
+}
+
+#[test]
+fn hash_lookup_index_test() {
+    assert_eq!(HASH_LOOKUP[&"hallo"], "hallo.example.com");
+}
+
+#[test]
+fn hash_lookup_keeps_entries_together() {
+    for (key, value) in HASH_LOOKUP.keys.iter().zip(HASH_LOOKUP.values) {
+        assert!(value.starts_with(key));
+    }
+}
+
+#[test]
+fn hash_lookup_works_for_empty() {
+    const EMPTY: ConstHashLookup<0, u8, u8> = ConstHashLookup::new([], []);
+
+    assert!(EMPTY.is_empty());
+    assert_eq!(EMPTY.get(&0), None);
+}
+
+#[test]
+fn hash_lookup_works_for_large_const() {
+    const LARGE: ConstHashLookup<2000, u32, u32> =
+        ConstHashLookup::new(numbers::<2000>(7919), numbers::<2000>(1));
+
+    for i in 0..2000 {
+        assert_eq!(LARGE.get(&(i * 7919)), Some(&i));
+        assert_eq!(LARGE.get(&(i * 7919 + 1)), None);
+    }
+}
+
+#[test]
+#[should_panic(expected = "duplicate key in ConstHashLookup")]
+fn hash_lookup_rejects_duplicate_keys() {
+    ConstHashLookup::new(['a', 'b', 'a'], [1, 2, 3]);
+}
+
+#[test]
+fn hash_lookup_works_for_tuple_keys() {
+    const TUPLES: ConstHashLookup<3, (&str, u8), u8> =
+        ConstHashLookup::new([("a", 1), ("a", 2), ("b", 1)], [1, 2, 3]);
+
+    assert_eq!(TUPLES.get(&("a", 2)), Some(&2));
+    assert_eq!(TUPLES.get(&("b", 1)), Some(&3));
+    assert_eq!(TUPLES.get(&("b", 2)), None);
+}
+
+#[test]
+fn hash_lookup_works_for_uncased_keys() {
+    use crate::UncasedStr;
+
+    const HEADERS: ConstHashLookup<2, &UncasedStr, u8> = ConstHashLookup::new(
+        [UncasedStr::new("Content-Type"), UncasedStr::new("Accept")],
+        [1, 2],
+    );
+
+    assert_eq!(HEADERS.get(UncasedStr::new("content-type")), Some(&1));
+    assert_eq!(HEADERS.get(UncasedStr::new("ACCEPT")), Some(&2));
+    assert_eq!(HEADERS.get(UncasedStr::new("Accepts")), None);
+}
@@ -0,0 +1,183 @@
+use core::cmp::Ordering;
+
+use crate::key::{self, ConstKey};
+use crate::ConstLookup;
+
+/// Number of interpolation probes before the search falls back to a binary search.
+const MAX_PROBES: usize = 8;
+
+/// Map with integer keys that can be defined in a const context, searched with interpolation search.
+///
+/// Instead of halving the range like a binary search, every probe guesses the position of the key from its distance
+/// to the first and last key in the range. For keys that are spread evenly this finds a key in a few probes instead of
+/// `O(log N)`. Keys that are not spread evenly fall back to a binary search after a few probes, so a lookup never does
+/// much worse than [`ConstLookup::get`].
+///
+/// ```rust
+/// use const_lookup_map::{ConstInterpolationLookup, interpolation_lookup};
+///
+/// const PORTS: ConstInterpolationLookup<4, u16, &str> = interpolation_lookup! {
+///     80 => "http",
+///     22 => "ssh",
+///     443 => "https",
+///     21 => "ftp",
+/// };
+///
+/// assert_eq!(PORTS.get(&443), Some(&"https"));
+/// assert_eq!(PORTS.get(&8080), None);
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstInterpolationLookup<const N: usize, K: Ord, V> {
+    lookup: ConstLookup<N, K, V>,
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> ConstInterpolationLookup<N, K, V> {
+    /// Creates the map from a sorted lookup, this can be used in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are not integers, are out of order or contain duplicates.
+    pub const fn new(lookup: ConstLookup<N, K, V>) -> ConstInterpolationLookup<N, K, V> {
+        assert!(
+            key::is_integer::<K>(),
+            "keys of ConstInterpolationLookup must be integers"
+        );
+        ConstInterpolationLookup {
+            lookup: lookup.checked(),
+        }
+    }
+
+    /// Returns the number of elements in the map.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the map that is searched, to use the rest of the [`ConstLookup`] methods.
+    pub const fn lookup(&self) -> &ConstLookup<N, K, V> {
+        &self.lookup
+    }
+
+    fn search(&self, key: &K) -> Option<usize> {
+        let keys = &self.lookup.keys;
+        let target = key::ordinal(key);
+        let (mut low, mut high) = (0, N);
+        let mut probes = 0;
+        while low < high && probes < MAX_PROBES {
+            let first = key::ordinal(&keys[low]);
+            let last = key::ordinal(&keys[high - 1]);
+            if target < first || target > last {
+                return None;
+            }
+            let index = low + interpolate(target - first, last - first, high - 1 - low);
+            match keys[index].cmp(key) {
+                Ordering::Less => low = index + 1,
+                Ordering::Greater => high = index,
+                Ordering::Equal => return Some(index),
+            }
+            probes += 1;
+        }
+        let index = keys[low..high].binary_search(key).ok()?;
+        Some(low + index)
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let index = self.search(key)?;
+        self.lookup.values.get(index)
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.search(key).is_some()
+    }
+}
+
+/// Returns the offset of `distance` in `span` scaled to `len`, rounded down.
+fn interpolate(distance: u128, span: u128, len: usize) -> usize {
+    if span == 0 {
+        return 0;
+    }
+    // dividing a `u64` is much faster, and enough for keys of up to 32 bits
+    let offset = match (distance as u64).checked_mul(len as u64) {
+        Some(product) if span <= u64::MAX as u128 => (product / span as u64) as u128,
+        _ => {
+            // drop the low bits of large spans, so the multiplication cannot overflow
+            let shift = (128 - span.leading_zeros()).saturating_sub(64);
+            (distance >> shift) * len as u128 / (span >> shift)
+        }
+    };
+    (offset as usize).min(len)
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> core::ops::Index<&K>
+    for ConstInterpolationLookup<N, K, V>
+{
+    type Output = V;
+
+    fn index(&self, index: &K) -> &V {
+        self.get(index)
+            .expect("key not found in ConstInterpolationLookup, use `get` for a safe option")
+    }
+}
+
+#[cfg(test)]
+const fn spread<const N: usize>() -> [i64; N] {
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = (i as i64 - 500) * 100 + (i as i64 * 37) % 90;
+        i += 1;
+    }
+    keys
+}
+
+#[cfg(test)]
+const SPREAD: ConstInterpolationLookup<1000, i64, usize> =
+    ConstInterpolationLookup::new(ConstLookup::new(spread::<1000>(), {
+        let mut values = [0; 1000];
+        let mut i = 0;
+        while i < 1000 {
+            values[i] = i;
+            i += 1;
+        }
+        values
+    }));
+
+#[test]
+fn interpolation_lookup_finds_every_key() {
+    for (index, key) in spread::<1000>().iter().enumerate() {
+        assert_eq!(SPREAD.get(key), Some(&index));
+        assert_eq!(SPREAD.get(&(key + 1)), None);
+    }
+    assert_eq!(SPREAD.get(&i64::MIN), None);
+    assert_eq!(SPREAD.get(&i64::MAX), None);
+}
+
+#[test]
+fn interpolation_lookup_works_for_skewed_keys() {
+    const SKEWED: ConstInterpolationLookup<6, u64, u8> = crate::interpolation_lookup! {
+        1 => 1,
+        2 => 2,
+        3 => 3,
+        4 => 4,
+        u64::MAX - 1 => 5,
+        u64::MAX => 6,
+    };
+    const EMPTY: ConstInterpolationLookup<0, u8, u8> = crate::interpolation_lookup! {};
+
+    assert_eq!(SKEWED.get(&3), Some(&3));
+    assert_eq!(SKEWED[&u64::MAX], 6);
+    assert!(!SKEWED.contains_key(&5));
+    assert!(!EMPTY.contains_key(&0));
+}
+
+#[test]
+#[should_panic(expected = "keys of ConstInterpolationLookup must be integers")]
+fn interpolation_lookup_rejects_other_keys() {
+    ConstInterpolationLookup::new(crate::lookup! { 'a' => 1 });
+}
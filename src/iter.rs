@@ -0,0 +1,157 @@
+use core::iter::{FusedIterator, Zip};
+use core::{array, slice};
+
+use crate::eytzinger;
+
+macro_rules! impl_iterator {
+    ($name:ident<$($lt:lifetime,)? $($param:ident),*> => $item:ty) => {
+        impl<$($lt,)? $($param),*> Iterator for $name<$($lt,)? $($param),*> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next()
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<$($lt,)? $($param),*> DoubleEndedIterator for $name<$($lt,)? $($param),*> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back()
+            }
+        }
+
+        impl<$($lt,)? $($param),*> ExactSizeIterator for $name<$($lt,)? $($param),*> {}
+
+        impl<$($lt,)? $($param),*> FusedIterator for $name<$($lt,)? $($param),*> {}
+    };
+}
+
+/// An iterator over the entries of a [`ConstLookup`](crate::ConstLookup), in key order.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V> {
+    inner: Zip<slice::Iter<'a, K>, slice::Iter<'a, V>>,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    pub(crate) fn new(keys: &'a [K], values: &'a [V]) -> Self {
+        Iter {
+            inner: keys.iter().zip(values),
+        }
+    }
+}
+
+impl_iterator!(Iter<'a, K, V> => (&'a K, &'a V));
+
+/// An iterator over the keys of a [`ConstLookup`](crate::ConstLookup) or [`ConstLookupSet`](crate::ConstLookupSet),
+/// in order.
+#[derive(Debug, Clone)]
+pub struct Keys<'a, K> {
+    pub(crate) inner: slice::Iter<'a, K>,
+}
+
+impl_iterator!(Keys<'a, K> => &'a K);
+
+/// An iterator over the values of a [`ConstLookup`](crate::ConstLookup), in key order.
+#[derive(Debug, Clone)]
+pub struct Values<'a, V> {
+    pub(crate) inner: slice::Iter<'a, V>,
+}
+
+impl_iterator!(Values<'a, V> => &'a V);
+
+/// A mutable iterator over the values of a [`ConstLookup`](crate::ConstLookup), in key order.
+#[derive(Debug)]
+pub struct ValuesMut<'a, V> {
+    pub(crate) inner: slice::IterMut<'a, V>,
+}
+
+impl_iterator!(ValuesMut<'a, V> => &'a mut V);
+
+/// An iterator over the entries of a [`ConstEytzingerLookup`](crate::ConstEytzingerLookup), in key order.
+#[derive(Debug, Clone)]
+pub struct EytzingerIter<'a, K, V> {
+    keys: &'a [K],
+    values: &'a [V],
+    front: usize,
+    back: usize,
+    remaining: usize,
+}
+
+impl<'a, K, V> EytzingerIter<'a, K, V> {
+    pub(crate) fn new(keys: &'a [K], values: &'a [V]) -> Self {
+        EytzingerIter {
+            keys,
+            values,
+            front: eytzinger::first(keys.len()),
+            back: eytzinger::last(keys.len()),
+            remaining: keys.len(),
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for EytzingerIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let node = self.front;
+        self.front = eytzinger::successor(node, self.keys.len());
+        Some((&self.keys[node - 1], &self.values[node - 1]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for EytzingerIter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let node = self.back;
+        self.back = eytzinger::predecessor(node, self.keys.len());
+        Some((&self.keys[node - 1], &self.values[node - 1]))
+    }
+}
+
+impl<K, V> ExactSizeIterator for EytzingerIter<'_, K, V> {}
+
+impl<K, V> FusedIterator for EytzingerIter<'_, K, V> {}
+
+/// An owning iterator over the entries of a [`ConstLookup`](crate::ConstLookup), in key order.
+#[derive(Debug, Clone)]
+pub struct IntoIter<const N: usize, K, V> {
+    inner: Zip<array::IntoIter<K, N>, array::IntoIter<V, N>>,
+}
+
+impl<const N: usize, K, V> IntoIter<N, K, V> {
+    pub(crate) fn new(keys: [K; N], values: [V; N]) -> Self {
+        IntoIter {
+            inner: keys.into_iter().zip(values),
+        }
+    }
+}
+
+impl<const N: usize, K, V> Iterator for IntoIter<N, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<const N: usize, K, V> DoubleEndedIterator for IntoIter<N, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<const N: usize, K, V> ExactSizeIterator for IntoIter<N, K, V> {}
+
+impl<const N: usize, K, V> FusedIterator for IntoIter<N, K, V> {}
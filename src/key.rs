@@ -0,0 +1,560 @@
+use core::cmp::Ordering;
+use core::mem::{size_of, MaybeUninit};
+
+use crate::UncasedStr;
+
+mod private {
+    pub trait Sealed {}
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyKind {
+    Unsigned,
+    Signed,
+    Char,
+    Bool,
+    Float,
+    Str,
+    UncasedStr,
+    Unit,
+    Pair,
+    Triple,
+}
+
+/// Key types that can be compared while evaluating a `const` item.
+///
+/// This is what allows [`lookup!`](crate::lookup) to sort its entries at compile time. It is
+/// implemented for all integer types, `char`, `bool`, `&str`, `&UncasedStr` and tuples of two or
+/// three of those, and cannot be implemented outside of this crate. Floats are compared with their
+/// `total_cmp`, use them with the [`TotalCmp`](crate::TotalCmp) comparator.
+pub trait ConstKey: private::Sealed {
+    #[doc(hidden)]
+    const KIND: KeyKind;
+    #[doc(hidden)]
+    type First: ConstKey;
+    #[doc(hidden)]
+    type Second: ConstKey;
+    #[doc(hidden)]
+    type Third: ConstKey;
+}
+
+macro_rules! impl_const_key {
+    ($kind:ident => $($ty:ty),*) => {
+        $(
+            impl private::Sealed for $ty {}
+            impl ConstKey for $ty {
+                const KIND: KeyKind = KeyKind::$kind;
+                type First = ();
+                type Second = ();
+                type Third = ();
+            }
+        )*
+    };
+}
+
+impl_const_key!(Unit => ());
+
+impl_const_key!(Unsigned => u8, u16, u32, u64, u128, usize);
+impl_const_key!(Signed => i8, i16, i32, i64, i128, isize);
+impl_const_key!(Char => char);
+impl_const_key!(Bool => bool);
+impl_const_key!(Float => f32, f64);
+
+impl<T: private::Sealed + ?Sized> private::Sealed for &T {}
+impl ConstKey for &str {
+    const KIND: KeyKind = KeyKind::Str;
+    type First = ();
+    type Second = ();
+    type Third = ();
+}
+
+impl ConstKey for &UncasedStr {
+    const KIND: KeyKind = KeyKind::UncasedStr;
+    type First = ();
+    type Second = ();
+    type Third = ();
+}
+
+impl<A: private::Sealed, B: private::Sealed> private::Sealed for (A, B) {}
+impl<A: ConstKey, B: ConstKey> ConstKey for (A, B) {
+    const KIND: KeyKind = KeyKind::Pair;
+    type First = A;
+    type Second = B;
+    type Third = ();
+}
+
+impl<A: private::Sealed, B: private::Sealed, C: private::Sealed> private::Sealed for (A, B, C) {}
+impl<A: ConstKey, B: ConstKey, C: ConstKey> ConstKey for (A, B, C) {
+    const KIND: KeyKind = KeyKind::Triple;
+    type First = A;
+    type Second = B;
+    type Third = C;
+}
+
+/// Reinterprets `key` as a `T`.
+///
+/// # Safety
+///
+/// `K` and `T` must be the same type, which the `KIND` of a sealed `ConstKey` guarantees.
+const unsafe fn cast<K, T>(key: &K) -> &T {
+    &*(key as *const K as *const T)
+}
+
+const fn to_u128<K: ConstKey>(key: &K) -> u128 {
+    // SAFETY: only called for `KeyKind::Unsigned`, so `K` is the matching unsigned integer.
+    unsafe {
+        match core::mem::size_of::<K>() {
+            1 => *cast::<K, u8>(key) as u128,
+            2 => *cast::<K, u16>(key) as u128,
+            4 => *cast::<K, u32>(key) as u128,
+            8 => *cast::<K, u64>(key) as u128,
+            _ => *cast::<K, u128>(key),
+        }
+    }
+}
+
+const fn to_i128<K: ConstKey>(key: &K) -> i128 {
+    // SAFETY: only called for `KeyKind::Signed`, so `K` is the matching signed integer.
+    unsafe {
+        match core::mem::size_of::<K>() {
+            1 => *cast::<K, i8>(key) as i128,
+            2 => *cast::<K, i16>(key) as i128,
+            4 => *cast::<K, i32>(key) as i128,
+            8 => *cast::<K, i64>(key) as i128,
+            _ => *cast::<K, i128>(key),
+        }
+    }
+}
+
+/// Maps a float to an integer with the same order as `total_cmp`.
+const fn to_total_i128<K: ConstKey>(key: &K) -> i128 {
+    // SAFETY: only called for `KeyKind::Float`, so `K` is the matching float.
+    unsafe {
+        match core::mem::size_of::<K>() {
+            4 => {
+                let bits = cast::<K, f32>(key).to_bits() as i32;
+                (bits ^ (((bits >> 31) as u32) >> 1) as i32) as i128
+            }
+            _ => {
+                let bits = cast::<K, f64>(key).to_bits() as i64;
+                (bits ^ (((bits >> 63) as u64) >> 1) as i64) as i128
+            }
+        }
+    }
+}
+
+macro_rules! cmp_primitive {
+    ($a:expr, $b:expr) => {{
+        let (a, b) = ($a, $b);
+        if a < b {
+            Ordering::Less
+        } else if a > b {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }};
+}
+
+/// Compares the bytes of two strings, ASCII letters are compared as lowercase if `ignore_case` is set.
+pub(crate) const fn cmp_str(a: &str, b: &str, ignore_case: bool) -> Ordering {
+    let (mut a, mut b) = (a.as_bytes(), b.as_bytes());
+    // slice patterns instead of indexing, the bounds checks add up when sorting large tables in const
+    while let ([x, rest_a @ ..], [y, rest_b @ ..]) = (a, b) {
+        let (x, y) = if ignore_case {
+            (x.to_ascii_lowercase(), y.to_ascii_lowercase())
+        } else {
+            (*x, *y)
+        };
+        if x != y {
+            return cmp_primitive!(x, y);
+        }
+        (a, b) = (rest_a, rest_b);
+    }
+    cmp_primitive!(a.len(), b.len())
+}
+
+const fn fold(a: u64, b: u64) -> u64 {
+    let full = (a as u128) * (b as u128);
+    (full as u64) ^ ((full >> 64) as u64)
+}
+
+const fn mix(a: u64, b: u64) -> u64 {
+    fold(a ^ 0xa076_1d64_78bd_642f, b ^ 0xe703_7ed1_a0b4_28db)
+}
+
+const fn hash_u128(value: u128, seed: u64) -> u64 {
+    mix(mix(seed, value as u64), (value >> 64) as u64)
+}
+
+/// Hashes the bytes of a string, ASCII letters are hashed as lowercase if `ignore_case` is set.
+pub(crate) const fn hash_str(value: &str, seed: u64, ignore_case: bool) -> u64 {
+    let bytes = value.as_bytes();
+    let mut hash = mix(seed, bytes.len() as u64);
+    let mut i = 0;
+    while i < bytes.len() {
+        let mut chunk = 0u64;
+        let mut j = 0;
+        while j < 8 && i + j < bytes.len() {
+            let byte = if ignore_case {
+                bytes[i + j].to_ascii_lowercase()
+            } else {
+                bytes[i + j]
+            };
+            chunk |= (byte as u64) << (j * 8);
+            j += 1;
+        }
+        hash = mix(hash, chunk);
+        i += 8;
+    }
+    hash
+}
+
+/// Hashes a key in a const context, keys that are equal have the same hash.
+pub(crate) const fn hash<K: ConstKey>(key: &K, seed: u64) -> u64 {
+    // SAFETY: `K::KIND` is set by the sealed implementations above and names the type of `K`.
+    unsafe {
+        match K::KIND {
+            KeyKind::Unsigned => hash_u128(to_u128(key), seed),
+            KeyKind::Signed => hash_u128(to_i128(key) as u128, seed),
+            KeyKind::Char => hash_u128(*cast::<K, char>(key) as u128, seed),
+            KeyKind::Bool => hash_u128(*cast::<K, bool>(key) as u128, seed),
+            KeyKind::Float => hash_u128(to_total_i128(key) as u128, seed),
+            KeyKind::Str => hash_str(cast::<K, &str>(key), seed, false),
+            KeyKind::UncasedStr => hash_str(cast::<K, &UncasedStr>(key).as_str(), seed, true),
+            KeyKind::Unit => seed,
+            KeyKind::Pair => {
+                let (a, b) = cast::<K, (K::First, K::Second)>(key);
+                hash(b, hash(a, seed))
+            }
+            KeyKind::Triple => {
+                let (a, b, c) = cast::<K, (K::First, K::Second, K::Third)>(key);
+                hash(c, hash(b, hash(a, seed)))
+            }
+        }
+    }
+}
+
+/// Borrowed forms of [`ConstKey`] types that can be hashed at runtime, like `str` for `&str` keys.
+///
+/// The hash is the same one that [`ConstHashLookup`](crate::ConstHashLookup) uses for the key while creating the
+/// map. It cannot be implemented outside of this crate.
+pub trait KeyHash: private::Sealed {
+    #[doc(hidden)]
+    fn key_hash(&self, seed: u64) -> u64;
+}
+
+macro_rules! impl_key_hash {
+    ($($ty:ty),*) => {
+        $(
+            impl KeyHash for $ty {
+                fn key_hash(&self, seed: u64) -> u64 {
+                    hash(self, seed)
+                }
+            }
+        )*
+    };
+}
+
+impl_key_hash!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    char,
+    bool,
+    ()
+);
+
+impl private::Sealed for str {}
+impl KeyHash for str {
+    fn key_hash(&self, seed: u64) -> u64 {
+        hash_str(self, seed, false)
+    }
+}
+
+impl private::Sealed for UncasedStr {}
+impl KeyHash for UncasedStr {
+    fn key_hash(&self, seed: u64) -> u64 {
+        hash_str(self.as_str(), seed, true)
+    }
+}
+
+impl<T: KeyHash + ?Sized> KeyHash for &T {
+    fn key_hash(&self, seed: u64) -> u64 {
+        T::key_hash(self, seed)
+    }
+}
+
+impl<A: KeyHash, B: KeyHash> KeyHash for (A, B) {
+    fn key_hash(&self, seed: u64) -> u64 {
+        self.1.key_hash(self.0.key_hash(seed))
+    }
+}
+
+impl<A: KeyHash, B: KeyHash, C: KeyHash> KeyHash for (A, B, C) {
+    fn key_hash(&self, seed: u64) -> u64 {
+        self.2.key_hash(self.1.key_hash(self.0.key_hash(seed)))
+    }
+}
+
+/// Keys that start with a prefix of type `P`, like tuples start with their first elements.
+///
+/// This is used by [`ConstLookup::prefix_range`](crate::ConstLookup::prefix_range). It is implemented for tuples of
+/// two to four elements, with the first element, or the first elements as a tuple, as prefix.
+pub trait KeyPrefix<P> {
+    /// Compares the prefix of this key with `prefix`.
+    fn cmp_prefix(&self, prefix: &P) -> Ordering;
+}
+
+impl<A: Ord, B> KeyPrefix<A> for (A, B) {
+    fn cmp_prefix(&self, prefix: &A) -> Ordering {
+        self.0.cmp(prefix)
+    }
+}
+
+impl<A: Ord, B, C> KeyPrefix<A> for (A, B, C) {
+    fn cmp_prefix(&self, prefix: &A) -> Ordering {
+        self.0.cmp(prefix)
+    }
+}
+
+impl<A: Ord, B: Ord, C> KeyPrefix<(A, B)> for (A, B, C) {
+    fn cmp_prefix(&self, prefix: &(A, B)) -> Ordering {
+        (&self.0, &self.1).cmp(&(&prefix.0, &prefix.1))
+    }
+}
+
+impl<A: Ord, B, C, D> KeyPrefix<A> for (A, B, C, D) {
+    fn cmp_prefix(&self, prefix: &A) -> Ordering {
+        self.0.cmp(prefix)
+    }
+}
+
+impl<A: Ord, B: Ord, C, D> KeyPrefix<(A, B)> for (A, B, C, D) {
+    fn cmp_prefix(&self, prefix: &(A, B)) -> Ordering {
+        (&self.0, &self.1).cmp(&(&prefix.0, &prefix.1))
+    }
+}
+
+impl<A: Ord, B: Ord, C: Ord, D> KeyPrefix<(A, B, C)> for (A, B, C, D) {
+    fn cmp_prefix(&self, prefix: &(A, B, C)) -> Ordering {
+        (&self.0, &self.1, &self.2).cmp(&(&prefix.0, &prefix.1, &prefix.2))
+    }
+}
+
+/// Mixes a hash into a second, independent looking, hash.
+pub(crate) const fn rehash(hash: u64) -> u64 {
+    mix(hash, 0x8ebc_6af0_9c88_c6e3)
+}
+
+/// Compares two keys in a const context, matching their `Ord` implementation or `total_cmp` for floats.
+pub(crate) const fn cmp<K: ConstKey>(a: &K, b: &K) -> Ordering {
+    // SAFETY: `K::KIND` is set by the sealed implementations above and names the type of `K`.
+    unsafe {
+        match K::KIND {
+            KeyKind::Unsigned => cmp_primitive!(to_u128(a), to_u128(b)),
+            KeyKind::Signed => cmp_primitive!(to_i128(a), to_i128(b)),
+            KeyKind::Char => cmp_primitive!(*cast::<K, char>(a), *cast::<K, char>(b)),
+            KeyKind::Bool => cmp_primitive!(*cast::<K, bool>(a) as u8, *cast::<K, bool>(b) as u8),
+            KeyKind::Float => cmp_primitive!(to_total_i128(a), to_total_i128(b)),
+            KeyKind::Str => cmp_str(cast::<K, &str>(a), cast::<K, &str>(b), false),
+            KeyKind::UncasedStr => cmp_str(
+                cast::<K, &UncasedStr>(a).as_str(),
+                cast::<K, &UncasedStr>(b).as_str(),
+                true,
+            ),
+            KeyKind::Unit => Ordering::Equal,
+            KeyKind::Pair => {
+                let a = cast::<K, (K::First, K::Second)>(a);
+                let b = cast::<K, (K::First, K::Second)>(b);
+                match cmp(&a.0, &b.0) {
+                    Ordering::Equal => cmp(&a.1, &b.1),
+                    ordering => ordering,
+                }
+            }
+            KeyKind::Triple => {
+                let a = cast::<K, (K::First, K::Second, K::Third)>(a);
+                let b = cast::<K, (K::First, K::Second, K::Third)>(b);
+                match cmp(&a.0, &b.0) {
+                    Ordering::Equal => match cmp(&a.1, &b.1) {
+                        Ordering::Equal => cmp(&a.2, &b.2),
+                        ordering => ordering,
+                    },
+                    ordering => ordering,
+                }
+            }
+        }
+    }
+}
+
+/// Sorts `keys` in a const context and applies the same permutation to `values`.
+///
+/// The sort is stable, so entries with equal keys keep the order they were written in.
+pub(crate) const fn sort_entries<const N: usize, K: ConstKey, V>(
+    keys: &mut [K; N],
+    values: &mut [V; N],
+) {
+    // Tables that are written in order, like generated ones, skip the sort, which is most of the const evaluation.
+    if is_strictly_sorted(keys) {
+        return;
+    }
+    // Sort the indices first, so the keys and values only need to be moved once.
+    let order = sort_order(keys);
+    permute(keys, values, &order);
+}
+
+/// Returns the indices of `keys` in sorted order, using a stable merge sort.
+///
+/// Every step counts towards the `long_running_const_eval` lint, which this stays under for about 10k shuffled `u32`
+/// keys and 8k shuffled `&str` keys of 8 bytes.
+pub(crate) const fn sort_order<const N: usize, K: ConstKey>(keys: &[K; N]) -> [usize; N] {
+    let mut order = [0usize; N];
+    let mut i = 0;
+    while i < N {
+        order[i] = i;
+        i += 1;
+    }
+
+    let mut buffer = [0usize; N];
+    let mut width = 1;
+    while width < N {
+        let mut low = 0;
+        while low < N {
+            let mid = if low + width < N { low + width } else { N };
+            let high = if low + 2 * width < N {
+                low + 2 * width
+            } else {
+                N
+            };
+            let (mut left, mut right, mut out) = (low, mid, low);
+            while out < high {
+                if left < mid
+                    && (right >= high || !cmp(&keys[order[right]], &keys[order[left]]).is_lt())
+                {
+                    buffer[out] = order[left];
+                    left += 1;
+                } else {
+                    buffer[out] = order[right];
+                    right += 1;
+                }
+                out += 1;
+            }
+            low = high;
+        }
+        order = buffer;
+        width *= 2;
+    }
+
+    order
+}
+
+/// Moves the entry at `order[target]` to `target`, for every `target`.
+pub(crate) const fn permute<const N: usize, K, V>(
+    keys: &mut [K; N],
+    values: &mut [V; N],
+    order: &[usize; N],
+) {
+    // `position[original]` is where an entry currently is, `original[position]` the reverse.
+    let mut position = [0usize; N];
+    let mut original = [0usize; N];
+    let mut i = 0;
+    while i < N {
+        position[i] = i;
+        original[i] = i;
+        i += 1;
+    }
+
+    let mut target = 0;
+    while target < N {
+        let source = position[order[target]];
+        keys.swap(target, source);
+        values.swap(target, source);
+
+        let displaced = original[target];
+        position[displaced] = source;
+        original[source] = displaced;
+        position[order[target]] = target;
+        original[target] = order[target];
+        target += 1;
+    }
+}
+
+/// Returns true if the keys are integers, which [`ordinal`] can map to a `u128`.
+pub(crate) const fn is_integer<K: ConstKey>() -> bool {
+    matches!(K::KIND, KeyKind::Unsigned | KeyKind::Signed)
+}
+
+/// Maps an integer key to a `u128` with the same order, so the distance between keys can be calculated.
+pub(crate) const fn ordinal<K: ConstKey>(key: &K) -> u128 {
+    match K::KIND {
+        KeyKind::Unsigned => to_u128(key),
+        KeyKind::Signed => to_i128(key) as u128 ^ (1 << 127),
+        _ => panic!("key is not an integer"),
+    }
+}
+
+/// Creates the integer key that [`ordinal`] maps to `value`.
+///
+/// # Panics
+///
+/// Panics if the key type is not an integer or cannot hold the value.
+pub(crate) const fn from_ordinal<K: ConstKey>(value: u128) -> K {
+    let bits = match K::KIND {
+        KeyKind::Unsigned => value,
+        KeyKind::Signed => value ^ (1 << 127),
+        _ => panic!("key is not an integer"),
+    };
+    let mut key = MaybeUninit::<K>::uninit();
+    // SAFETY: `K` is an integer of `size_of::<K>()` bytes, so every bit pattern of that size is a valid `K`.
+    let key = unsafe {
+        match size_of::<K>() {
+            1 => (key.as_mut_ptr() as *mut u8).write(bits as u8),
+            2 => (key.as_mut_ptr() as *mut u16).write(bits as u16),
+            4 => (key.as_mut_ptr() as *mut u32).write(bits as u32),
+            8 => (key.as_mut_ptr() as *mut u64).write(bits as u64),
+            _ => (key.as_mut_ptr() as *mut u128).write(bits),
+        }
+        key.assume_init()
+    };
+    assert!(
+        ordinal(&key) == value,
+        "integer does not fit in the key type"
+    );
+    key
+}
+
+/// Returns true if every key is strictly smaller than the next one, so sorted without duplicates.
+pub(crate) const fn is_strictly_sorted<K: ConstKey>(keys: &[K]) -> bool {
+    let mut i = 1;
+    while i < keys.len() {
+        if !cmp(&keys[i - 1], &keys[i]).is_lt() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Binary searches the sorted `keys` in a const context, like [`slice::binary_search`].
+pub(crate) const fn binary_search<K: ConstKey>(keys: &[K], key: &K) -> Result<usize, usize> {
+    let (mut low, mut high) = (0, keys.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match cmp(&keys[mid], key) {
+            Ordering::Less => low = mid + 1,
+            Ordering::Greater => high = mid,
+            Ordering::Equal => return Ok(mid),
+        }
+    }
+    Err(low)
+}
@@ -28,7 +28,66 @@
 //! );
 //! ```
 //!
-//! One note; The keys should be in order/sorted because the get method will use this to effienctly get the value. See [`ConstLookup::check_sorted`]
+//! The `lookup!` macro can also declare the constant, then it counts the entries for you:
+//!
+//! ```rust
+//! use const_lookup_map::lookup;
+//!
+//! lookup! {
+//!     pub const LOOKUP: &str => &str = {
+//!         "best" => "better",
+//!         "test" => "testing",
+//!         "guessed" => "guessing",
+//!     };
+//! }
+//! ```
+//!
+//! One note; The keys should be in order/sorted because the get method will use this to effienctly get the value.
+//! The [`lookup!`] macro sorts the entries for you when the keys implement [`ConstKey`] (integers, `char`, `bool`, `&str`,
+//! [`&UncasedStr`](UncasedStr) and tuples of those), when using [`ConstLookup::new`] directly you have to sort them
+//! yourself. See [`ConstLookup::new_checked`] and [`ConstLookup::check_sorted`]
+//!
+//! The sorting happens while compiling, and rustc stops large tables with a "constant evaluation is taking a long time"
+//! error. Entries that are already written in order skip the sort and work up to about 50k keys, other tables up to
+//! about 10k integer keys or 8k string keys. Larger tables build with `#[allow(long_running_const_eval)]` on the
+//! constant.
+//!
+//! Other key types, like your own enums, can be used with `lookup!(unsorted; ...)`, which keeps the entries in the
+//! order they are written:
+//!
+//! ```rust
+//! use const_lookup_map::{ConstLookup, lookup};
+//!
+//! #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+//! enum Level {
+//!     Debug,
+//!     Info,
+//!     Error,
+//! }
+//!
+//! const NAMES: ConstLookup<3, Level, &str> = lookup! {
+//!     unsorted;
+//!     Level::Debug => "debug",
+//!     Level::Info => "info",
+//!     Level::Error => "error",
+//! };
+//!
+//! assert!(NAMES.check_sorted());
+//! assert_eq!(NAMES.get(&Level::Info), Some(&"info"));
+//! ```
+//!
+//! `unsorted;` also works in front of the declaring form, for all the constants in it.
+//!
+//! The keys are ordered by their `Ord` implementation, another [`Comparator`] can be given as last type parameter, like
+//! [`TotalCmp`] for float keys.
+//!
+//! # Features
+//!
+//! - `serde`: implements `Serialize` for [`ConstLookup`], as a map in key order.
+//! - `macros`: adds the `const_lookup!` procedural macro, which sorts literal keys, reports duplicate keys while
+//!   compiling and can declare the constant with the correct length.
+//! - `codegen`: adds the `codegen` module to generate lookups from CSV, JSON and TOML files in a `build.rs`,
+//!   this requires `std`.
 //!
 //! # Usage
 //!
@@ -48,87 +107,597 @@
 //! # my_function()
 //! ```
 
-fn is_sorted<I>(data: I) -> bool
+#[cfg(any(test, feature = "codegen"))]
+extern crate std;
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::size_of;
+use core::ops::{Bound, RangeBounds};
+
+mod bi;
+mod cmp;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+mod dense;
+mod eytzinger;
+mod hash;
+mod interpolation;
+pub mod iter;
+mod key;
+mod multi;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod set;
+mod uncased;
+
+pub use bi::ConstBiLookup;
+pub use cmp::{Comparator, ConstComparator, OrdCmp, TotalCmp};
+#[cfg(feature = "macros")]
+pub use const_lookup_map_macros::const_lookup;
+pub use dense::ConstDenseLookup;
+pub use eytzinger::ConstEytzingerLookup;
+pub use hash::ConstHashLookup;
+pub use interpolation::ConstInterpolationLookup;
+pub use key::{ConstKey, KeyHash, KeyPrefix};
+pub use multi::ConstMultiLookup;
+pub use set::ConstLookupSet;
+pub use uncased::UncasedStr;
+
+fn is_sorted<C: Comparator<K>, K>(keys: &[K]) -> bool {
+    keys.windows(2)
+        .all(|pair| C::cmp(&pair[0], &pair[1]) != Ordering::Greater)
+}
+
+/// Returns the number of keys that are smaller than `key`, or smaller or equal when `inclusive` is set.
+fn partition<C, K, Q>(keys: &[K], key: &Q, inclusive: bool) -> usize
 where
-    I: IntoIterator,
-    I::Item: Ord,
+    C: Comparator<Q>,
+    K: Borrow<Q>,
+    Q: ?Sized,
 {
-    let mut it = data.into_iter();
-    match it.next() {
-        None => true,
-        Some(first) => it
-            .scan(first, |state, next| {
-                let cmp = *state <= next;
-                *state = next;
-                Some(cmp)
-            })
-            .all(|b| b),
-    }
+    keys.partition_point(|probe| match C::cmp(probe.borrow(), key) {
+        Ordering::Less => true,
+        Ordering::Equal => inclusive,
+        Ordering::Greater => false,
+    })
 }
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct ConstLookup<const N: usize, K: Ord, V> {
+/// Returns the indices of the sorted keys that are in the range, empty if the start of the range is after the end.
+fn range_indices<C, K, Q, R>(keys: &[K], range: R) -> core::ops::Range<usize>
+where
+    C: Comparator<Q>,
+    K: Borrow<Q>,
+    Q: ?Sized,
+    R: RangeBounds<Q>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(key) => partition::<C, _, _>(keys, key, false),
+        Bound::Excluded(key) => partition::<C, _, _>(keys, key, true),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(key) => partition::<C, _, _>(keys, key, true),
+        Bound::Excluded(key) => partition::<C, _, _>(keys, key, false),
+        Bound::Unbounded => keys.len(),
+    };
+    start..end.max(start)
+}
+
+/// Map that can be defined in a const context, the keys are stored sorted and looked up with a binary search.
+///
+/// The keys are ordered by the comparator `C`, which is their `Ord` implementation unless another [`Comparator`] is
+/// given.
+pub struct ConstLookup<const N: usize, K, V, C = OrdCmp> {
     pub keys: [K; N],
     pub values: [V; N],
+    comparator: PhantomData<C>,
 }
 
 impl<const N: usize, K: Ord, V> ConstLookup<N, K, V> {
+    pub const fn new(keys: [K; N], values: [V; N]) -> ConstLookup<N, K, V> {
+        ConstLookup::with_comparator(keys, values)
+    }
+}
+
+impl<const N: usize, K, V, C: Comparator<K>> ConstLookup<N, K, V, C> {
+    /// Up to 8 keys that fit in a cache line are searched by comparing all of them, which is faster than the
+    /// unpredictable branches of a binary search for so few keys. From 16 `u8` or `u32` keys the binary search is
+    /// faster again, see `benches/small.rs`.
+    const LINEAR_SEARCH: bool = N <= 8 && N * size_of::<K>() <= 64;
+
     /// Returns the number of elements in the map.
     pub const fn len(&self) -> usize {
         N
     }
 
-    pub const fn new(keys: [K; N], values: [V; N]) -> ConstLookup<N, K, V> {
-        ConstLookup { keys, values }
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Creates the map with the comparator `C` instead of `Ord`, the keys have to be sorted by it.
+    pub const fn with_comparator(keys: [K; N], values: [V; N]) -> Self {
+        ConstLookup {
+            keys,
+            values,
+            comparator: PhantomData,
+        }
     }
 
-    /// because keys cannot be checked at compiletime if it is sorted, add this to your tests:
+    /// Returns true if the keys are sorted by the comparator.
     ///
-    /// ```rust
+    /// For keys that implement [`ConstKey`] use [`ConstLookup::new_checked`] to check this at compiletime,
+    /// for other keys this cannot be checked at compiletime, so add this to your tests:
+    ///
+    /// ```rust,no_run
+    /// # use const_lookup_map::ConstLookup;
+    /// # const MY_LOOKUP: ConstLookup<0, (u8, u8), ()> = ConstLookup::new([], []);
+    /// # #[allow(clippy::test_attr_in_doctest)]
     /// #[test]
     /// fn verify_my_lookup_is_sorted() {
     ///     assert!(MY_LOOKUP.check_sorted(), "MY_LOOKUP is not sorted")
     /// }
     /// ```
     pub fn check_sorted(&self) -> bool {
-        is_sorted(&self.keys)
+        is_sorted::<C, _>(&self.keys)
+    }
+
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        if Self::LINEAR_SEARCH {
+            // count the smaller keys without branching, so the loop can be vectorised, only the next key can be equal
+            let index = self
+                .keys
+                .iter()
+                .filter(|probe| C::cmp((*probe).borrow(), key) == Ordering::Less)
+                .count();
+            return match self.keys.get(index) {
+                Some(probe) if C::cmp(probe.borrow(), key) == Ordering::Equal => Ok(index),
+                _ => Err(index),
+            };
+        }
+        self.keys
+            .binary_search_by(|probe| C::cmp(probe.borrow(), key))
     }
 
     /// Returns a reference to the value corresponding to the key.
-    pub fn get(&self, key: &K) -> Option<&V> {
-        let index = self.keys.binary_search(key).ok()?;
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering on the borrowed form must match the
+    /// ordering on the key type. If the key is in the map more than once any of its values can be returned, use
+    /// [`ConstMultiLookup`] for maps with duplicate keys.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const LOOKUP: ConstLookup<2, &str, u8> = lookup! {
+    ///     "one" => 1,
+    ///     "two" => 2,
+    /// };
+    ///
+    /// let key = String::from("two");
+    /// assert_eq!(LOOKUP.get(key.as_str()), Some(&2));
+    /// assert_eq!(LOOKUP[key.as_str()], 2);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        let index = self.get_index_of(key)?;
         self.values.get(index)
     }
 
+    /// Returns the key-value pair corresponding to the key.
+    ///
+    /// This is useful to get the key stored in the map, like a `&'static str`, from a borrowed key.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const LOOKUP: ConstLookup<2, &str, u8> = lookup! {
+    ///     "one" => 1,
+    ///     "two" => 2,
+    /// };
+    ///
+    /// let key = String::from("two");
+    /// let (name, value): (&&'static str, _) = LOOKUP.get_key_value(key.as_str()).unwrap();
+    /// assert_eq!((*name, *value), ("two", 2));
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.get_index(self.get_index_of(key)?)
+    }
+
+    /// Returns the position of the key in the map.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.search(key).ok()
+    }
+
+    /// Returns the key-value pair at the position, positions are in key order.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const NAMES: ConstLookup<2, u8, &str> = lookup! {
+    ///     1 => "one",
+    ///     2 => "two",
+    /// };
+    /// const DUTCH: ConstLookup<2, u8, &str> = lookup! {
+    ///     1 => "een",
+    ///     2 => "twee",
+    /// };
+    ///
+    /// let index = NAMES.get_index_of(&2).unwrap();
+    /// assert_eq!(DUTCH.get_index(index), Some((&2, &"twee")));
+    /// ```
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        Some((self.keys.get(index)?, self.values.get(index)?))
+    }
+
     /// Returns true if the map contains a value for the specified key.
-    pub fn contains_key(&self, key: &K) -> bool {
-        self.keys.binary_search(key).is_ok()
+    ///
+    /// The key may be any borrowed form of the map's key type, but the ordering on the borrowed form must match the
+    /// ordering on the key type.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Returns an iterator over the entries with a key in the range, in key order.
+    ///
+    /// The iterator is empty if the start of the range is after the end.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const VERSIONS: ConstLookup<4, u32, &str> = lookup! {
+    ///     100 => "first",
+    ///     120 => "second",
+    ///     200 => "third",
+    ///     250 => "fourth",
+    /// };
+    ///
+    /// let names: Vec<_> = VERSIONS.range(110..=200).map(|(_, name)| *name).collect();
+    /// assert_eq!(names, ["second", "third"]);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> iter::Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+        R: RangeBounds<Q>,
+    {
+        let indices = range_indices::<C, _, _, _>(&self.keys, range);
+        iter::Iter::new(&self.keys[indices.clone()], &self.values[indices])
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Returns the entry with the largest key.
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.get_index(N.checked_sub(1)?)
+    }
+
+    /// Returns the entry with the greatest key that is smaller than or equal to `key`.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const VERSIONS: ConstLookup<3, u32, &str> = lookup! {
+    ///     100 => "first",
+    ///     120 => "second",
+    ///     200 => "third",
+    /// };
+    ///
+    /// assert_eq!(VERSIONS.floor_key_value(&150), Some((&120, &"second")));
+    /// assert_eq!(VERSIONS.floor_key_value(&120), Some((&120, &"second")));
+    /// assert_eq!(VERSIONS.floor_key_value(&99), None);
+    /// ```
+    pub fn floor_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.get_index(partition::<C, _, _>(&self.keys, key, true).checked_sub(1)?)
+    }
+
+    /// Returns the entry with the smallest key that is greater than or equal to `key`.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const VERSIONS: ConstLookup<3, u32, &str> = lookup! {
+    ///     100 => "first",
+    ///     120 => "second",
+    ///     200 => "third",
+    /// };
+    ///
+    /// assert_eq!(VERSIONS.ceiling_key_value(&150), Some((&200, &"third")));
+    /// assert_eq!(VERSIONS.ceiling_key_value(&120), Some((&120, &"second")));
+    /// assert_eq!(VERSIONS.ceiling_key_value(&201), None);
+    /// ```
+    pub fn ceiling_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        C: Comparator<Q>,
+    {
+        self.get_index(partition::<C, _, _>(&self.keys, key, false))
+    }
+
+    /// Returns an iterator over the entries of the map, in key order.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const LOOKUP: ConstLookup<2, &str, u8> = lookup! {
+    ///     "two" => 2,
+    ///     "one" => 1,
+    /// };
+    ///
+    /// let mut iter = LOOKUP.iter();
+    /// assert_eq!(iter.next(), Some((&"one", &1)));
+    /// assert_eq!(iter.next(), Some((&"two", &2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> iter::Iter<'_, K, V> {
+        iter::Iter::new(&self.keys, &self.values)
+    }
+
+    /// Returns an iterator over the keys of the map, in order.
+    pub fn keys(&self) -> iter::Keys<'_, K> {
+        iter::Keys {
+            inner: self.keys.iter(),
+        }
+    }
+
+    /// Returns an iterator over the values of the map, in key order.
+    pub fn values(&self) -> iter::Values<'_, V> {
+        iter::Values {
+            inner: self.values.iter(),
+        }
+    }
+
+    /// Returns a mutable iterator over the values of the map, in key order.
+    pub fn values_mut(&mut self) -> iter::ValuesMut<'_, V> {
+        iter::ValuesMut {
+            inner: self.values.iter_mut(),
+        }
+    }
+}
+
+impl<const N: usize, K: Ord, V> ConstLookup<N, K, V> {
+    /// Returns an iterator over the entries whose key starts with `prefix`, in key order.
+    ///
+    /// Tuple keys are sorted by their first elements first, so these entries are next to each other.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const CODES: ConstLookup<4, (&str, u16), &str> = lookup! {
+    ///     ("us", 2) => "Alaska",
+    ///     ("de", 1) => "Berlin",
+    ///     ("us", 1) => "Alabama",
+    ///     ("fr", 1) => "Paris",
+    /// };
+    ///
+    /// let names: Vec<_> = CODES.prefix_range(&"us").map(|(_, name)| *name).collect();
+    /// assert_eq!(names, ["Alabama", "Alaska"]);
+    /// ```
+    pub fn prefix_range<P>(&self, prefix: &P) -> iter::Iter<'_, K, V>
+    where
+        K: KeyPrefix<P>,
+    {
+        let start = self
+            .keys
+            .partition_point(|key| key.cmp_prefix(prefix) == Ordering::Less);
+        let end = start
+            + self.keys[start..].partition_point(|key| key.cmp_prefix(prefix) != Ordering::Greater);
+        iter::Iter::new(&self.keys[start..end], &self.values[start..end])
+    }
+}
+
+impl<const N: usize, K: Ord + Borrow<str>, V> ConstLookup<N, K, V> {
+    /// Returns the entry with the longest key that is a prefix of `input`.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const ROUTES: ConstLookup<3, &str, &str> = lookup! {
+    ///     "/" => "index",
+    ///     "/api" => "api",
+    ///     "/api/users" => "users",
+    /// };
+    ///
+    /// assert_eq!(ROUTES.longest_prefix_match("/api/users/7"), Some((&"/api/users", &"users")));
+    /// assert_eq!(ROUTES.longest_prefix_match("/apis"), Some((&"/api", &"api")));
+    /// assert_eq!(ROUTES.longest_prefix_match("api"), None);
+    /// ```
+    pub fn longest_prefix_match(&self, input: &str) -> Option<(&K, &V)> {
+        let mut input = input.as_bytes();
+        loop {
+            // the last key that is not greater than the input, a longer prefix would come after it
+            let index = self
+                .keys
+                .partition_point(|key| key.borrow().as_bytes() <= input)
+                .checked_sub(1)?;
+            let key = self.keys[index].borrow().as_bytes();
+            if input.starts_with(key) {
+                return self.get_index(index);
+            }
+            let common = key.iter().zip(input).take_while(|(a, b)| a == b).count();
+            input = &input[..common];
+        }
+    }
+
+    /// Returns an iterator over the entries whose key starts with `prefix`, in key order.
+    pub fn keys_with_prefix(&self, prefix: &str) -> iter::Iter<'_, K, V> {
+        let start = self.keys.partition_point(|key| key.borrow() < prefix);
+        let end =
+            start + self.keys[start..].partition_point(|key| key.borrow().starts_with(prefix));
+        iter::Iter::new(&self.keys[start..end], &self.values[start..end])
+    }
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> ConstLookup<N, K, V> {
+    /// Creates the map and checks that the keys are sorted and unique.
+    ///
+    /// In a const context this fails the build instead of creating a map that silently misses keys.
+    ///
+    /// ```rust
+    /// use const_lookup_map::ConstLookup;
+    ///
+    /// const LOOKUP: ConstLookup<3, u32, &str> =
+    ///     ConstLookup::new_checked([1, 2, 3], ["one", "two", "three"]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use const_lookup_map::ConstLookup;
+    ///
+    /// const LOOKUP: ConstLookup<3, u32, &str> =
+    ///     ConstLookup::new_checked([1, 3, 2], ["one", "three", "two"]);
+    /// # let _ = LOOKUP;
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are out of order or contain duplicates.
+    pub const fn new_checked(keys: [K; N], values: [V; N]) -> Self {
+        ConstLookup::new(keys, values).checked()
     }
 }
 
-// impl<const N: usize, K: Ord, V> ConstLookup<N, K, V> {
-//     pub const fn const_contains<Q: ~const PartialEq>(&self, key: &K) -> bool {
-//         let mut i = 0;
-//         while i < self.keys.len() {
-//             if key == &self.keys[i] {
-//                 return true;
-//             }
-//             i = i + 1;
-//         }
+impl<const N: usize, K: ConstKey, V, C: ConstComparator<K>> ConstLookup<N, K, V, C> {
+    /// Sorts the entries by key, this can be used in a const context.
+    ///
+    /// ```rust
+    /// use const_lookup_map::ConstLookup;
+    ///
+    /// const LOOKUP: ConstLookup<3, u32, &str> =
+    ///     ConstLookup::new([3, 1, 2], ["three", "one", "two"]).sorted();
+    ///
+    /// assert_eq!(LOOKUP.keys, [1, 2, 3]);
+    /// assert_eq!(LOOKUP.get(&1), Some(&"one"));
+    /// ```
+    pub const fn sorted(mut self) -> Self {
+        key::sort_entries(&mut self.keys, &mut self.values);
+        self
+    }
+
+    /// Checks that the keys are sorted and unique, this can be used in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are out of order or contain duplicates.
+    pub const fn checked(self) -> Self {
+        assert!(
+            key::is_strictly_sorted(&self.keys),
+            "keys of ConstLookup are not sorted or contain duplicates"
+        );
+        self
+    }
+
+    /// Returns a reference to the value corresponding to the key, this can be used in a const context.
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookup, lookup};
+    ///
+    /// const SIZES: ConstLookup<2, &str, usize> = lookup! {
+    ///     "small" => 4,
+    ///     "large" => 16,
+    /// };
+    ///
+    /// const BUFFER: [u8; *SIZES.const_get(&"large").unwrap()] = [0; 16];
+    /// ```
+    pub const fn const_get(&self, key: &K) -> Option<&V> {
+        match key::binary_search(&self.keys, key) {
+            Ok(index) => Some(&self.values[index]),
+            Err(_) => None,
+        }
+    }
 
-//         false
-//     }
-// }
+    /// Returns true if the map contains a value for the specified key, this can be used in a const context.
+    pub const fn const_contains_key(&self, key: &K) -> bool {
+        key::binary_search(&self.keys, key).is_ok()
+    }
+}
 
-impl<const N: usize, K: Ord, V> core::ops::Index<&K> for ConstLookup<N, K, V> {
+impl<const N: usize, K, V, C, Q> core::ops::Index<&Q> for ConstLookup<N, K, V, C>
+where
+    K: Borrow<Q>,
+    Q: ?Sized,
+    C: Comparator<K> + Comparator<Q>,
+{
     type Output = V;
 
-    fn index(&self, index: &K) -> &V {
+    fn index(&self, index: &Q) -> &V {
         self.get(index)
             .expect("key not found in ConstLookup, use `get` for a safe option")
     }
 }
 
+// not derived, so the comparator does not need to implement these traits
+
+impl<const N: usize, K: fmt::Debug, V: fmt::Debug, C> fmt::Debug for ConstLookup<N, K, V, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConstLookup")
+            .field("keys", &self.keys)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<const N: usize, K: PartialEq, V: PartialEq, C> PartialEq for ConstLookup<N, K, V, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.keys == other.keys && self.values == other.values
+    }
+}
+
+impl<const N: usize, K: Eq, V: Eq, C> Eq for ConstLookup<N, K, V, C> {}
+
+impl<const N: usize, K, V, C> IntoIterator for ConstLookup<N, K, V, C> {
+    type Item = (K, V);
+    type IntoIter = iter::IntoIter<N, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::IntoIter::new(self.keys, self.values)
+    }
+}
+
+impl<'a, const N: usize, K, V, C> IntoIterator for &'a ConstLookup<N, K, V, C> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = iter::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iter::Iter::new(&self.keys, &self.values)
+    }
+}
+
 #[cfg(test)]
 const LOOKUP: ConstLookup<4, &str, &str> = ConstLookup::new(
     ["bye", "hallo", "hey", "test"],
@@ -145,31 +714,149 @@ macro_rules! lookup {
     (@single $($x:tt)*) => (());
     (@count $($rest:expr),*) => (<[()]>::len(&[$(lookup!(@single $rest)),*]));
 
+    (@items [$($mode:tt)*]) => {};
+    (
+        @items [$($mode:tt)*]
+        $(#[$meta:meta])*
+        $vis:vis const $name:ident : $key_type:ty => $value_type:ty, $cmp:ty = { $($key:expr => $value:expr),* $(,)? };
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis const $name: $crate::ConstLookup<{ lookup!(@count $($key),*) }, $key_type, $value_type, $cmp> =
+            lookup!($($mode)* cmp: $cmp; $($key => $value),*);
+        lookup!(@items [$($mode)*] $($rest)*);
+    };
+    (
+        @items [$($mode:tt)*]
+        $(#[$meta:meta])*
+        $vis:vis static $name:ident : $key_type:ty => $value_type:ty, $cmp:ty = { $($key:expr => $value:expr),* $(,)? };
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis static $name: $crate::ConstLookup<{ lookup!(@count $($key),*) }, $key_type, $value_type, $cmp> =
+            lookup!($($mode)* cmp: $cmp; $($key => $value),*);
+        lookup!(@items [$($mode)*] $($rest)*);
+    };
+    (
+        @items [$($mode:tt)*]
+        $(#[$meta:meta])*
+        $vis:vis const $name:ident : $key_type:ty => $value_type:ty = { $($key:expr => $value:expr),* $(,)? };
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis const $name: $crate::ConstLookup<{ lookup!(@count $($key),*) }, $key_type, $value_type> =
+            lookup!($($mode)* $($key => $value),*);
+        lookup!(@items [$($mode)*] $($rest)*);
+    };
+    (
+        @items [$($mode:tt)*]
+        $(#[$meta:meta])*
+        $vis:vis static $name:ident : $key_type:ty => $value_type:ty = { $($key:expr => $value:expr),* $(,)? };
+        $($rest:tt)*
+    ) => {
+        $(#[$meta])*
+        $vis static $name: $crate::ConstLookup<{ lookup!(@count $($key),*) }, $key_type, $value_type> =
+            lookup!($($mode)* $($key => $value),*);
+        lookup!(@items [$($mode)*] $($rest)*);
+    };
+    ($(#[$meta:meta])* $vis:vis const $($rest:tt)*) => { lookup!(@items [] $(#[$meta])* $vis const $($rest)*); };
+    ($(#[$meta:meta])* $vis:vis static $($rest:tt)*) => { lookup!(@items [] $(#[$meta])* $vis static $($rest)*); };
+    (unsorted; $(#[$meta:meta])* $vis:vis const $($rest:tt)*) => {
+        lookup!(@items [unsorted;] $(#[$meta])* $vis const $($rest)*);
+    };
+    (unsorted; $(#[$meta:meta])* $vis:vis static $($rest:tt)*) => {
+        lookup!(@items [unsorted;] $(#[$meta])* $vis static $($rest)*);
+    };
+
+    (unsorted; cmp: $cmp:ty; $($key:expr => $value:expr),* $(,)?) => {{
+        let lookup: $crate::ConstLookup<{ lookup!(@count $($key),*) }, _, _, $cmp> =
+            $crate::ConstLookup::with_comparator([$($key),*], [$($value),*]);
+        lookup
+    }};
+    (cmp: $cmp:ty; $($key:expr => $value:expr),* $(,)?) => {{
+        let lookup: $crate::ConstLookup<{ lookup!(@count $($key),*) }, _, _, $cmp> =
+            $crate::ConstLookup::with_comparator([$($key),*], [$($value),*]);
+        lookup.sorted().checked()
+    }};
+    (unsorted; $($key:expr => $value:expr),* $(,)?) => {
+        $crate::ConstLookup::new([$($key),*], [$($value),*])
+    };
     ($($key:expr => $value:expr,)+) => { lookup!($($key => $value),+) };
     ($($key:expr => $value:expr),*) => {
-        {
-            const _CAP: usize = lookup!(@count $($key),*);
-            let mut keys: [_; _CAP] = unsafe {
-                let arr = core::mem::MaybeUninit::uninit();
-                arr.assume_init()
-            };
+        $crate::ConstLookup::new([$($key),*], [$($value),*])
+            .sorted()
+            .checked()
+    };
+}
 
-            let mut values: [_; _CAP] = unsafe {
-                let arr = core::mem::MaybeUninit::uninit();
-                arr.assume_init()
-            };
+/// Creates a [`ConstHashLookup`] the same way [`lookup!`] creates a [`ConstLookup`].
+#[macro_export]
+macro_rules! hash_lookup {
+    ($($key:expr => $value:expr,)+) => { $crate::hash_lookup!($($key => $value),+) };
+    ($($key:expr => $value:expr),*) => {
+        $crate::ConstHashLookup::new([$($key),*], [$($value),*])
+    };
+}
 
-            let mut i = 0;
-            $(
-                keys[i] = $key;
-                values[i] = $value;
-                i+=1;
-            )*
+/// Creates a [`ConstDenseLookup`] from the entries of a [`lookup!`], or from values with `offset: first key;` in front.
+#[macro_export]
+macro_rules! dense_lookup {
+    (offset: $offset:expr; $($value:expr),* $(,)?) => {
+        $crate::ConstDenseLookup::with_offset($offset, [$($value),*])
+    };
+    ($($entries:tt)*) => {
+        $crate::ConstDenseLookup::new($crate::lookup!($($entries)*))
+    };
+}
 
-            _ = i;
+/// Creates a [`ConstEytzingerLookup`] from the entries of a [`lookup!`].
+#[macro_export]
+macro_rules! eytzinger_lookup {
+    ($($entries:tt)*) => {
+        $crate::ConstEytzingerLookup::new($crate::lookup!($($entries)*))
+    };
+}
 
-            ConstLookup::new(keys, values)
-        }
+/// Creates a [`ConstInterpolationLookup`] from the entries of a [`lookup!`].
+#[macro_export]
+macro_rules! interpolation_lookup {
+    ($($entries:tt)*) => {
+        $crate::ConstInterpolationLookup::new($crate::lookup!($($entries)*))
+    };
+}
+
+/// Creates a [`ConstLookupSet`], the keys are sorted at compile time like in [`lookup!`].
+///
+/// ```rust
+/// use const_lookup_map::{ConstLookupSet, set};
+///
+/// const VOWELS: ConstLookupSet<5, char> = set!['u', 'o', 'i', 'e', 'a'];
+/// ```
+#[macro_export]
+macro_rules! set {
+    ($($key:expr,)+) => { $crate::set!($($key),+) };
+    ($($key:expr),*) => {
+        $crate::ConstLookupSet::new([$($key),*])
+            .sorted()
+            .checked()
+    };
+}
+
+/// Creates a [`ConstMultiLookup`] the same way [`lookup!`] creates a [`ConstLookup`], but keys may be repeated.
+#[macro_export]
+macro_rules! multi_lookup {
+    ($($key:expr => $value:expr,)+) => { $crate::multi_lookup!($($key => $value),+) };
+    ($($key:expr => $value:expr),*) => {
+        $crate::ConstMultiLookup::new([$($key),*], [$($value),*]).sorted()
+    };
+}
+
+/// Creates a [`ConstBiLookup`] the same way [`lookup!`] creates a [`ConstLookup`].
+#[macro_export]
+macro_rules! bi_lookup {
+    ($($key:expr => $value:expr,)+) => { $crate::bi_lookup!($($key => $value),+) };
+    ($($key:expr => $value:expr),*) => {
+        $crate::ConstBiLookup::new([$($key),*], [$($value),*])
     };
 }
 
@@ -193,6 +880,137 @@ fn index_test() {
     assert_eq!("hey.example.com", LOOKUP[&"hey"]);
 }
 
+#[test]
+fn iter_test() {
+    let mut iter = LOOKUP.iter();
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next(), Some((&"bye", &"bye.example.com")));
+    assert_eq!(iter.next_back(), Some((&"test", &"test.example.com")));
+    assert_eq!(iter.len(), 2);
+    assert!(LOOKUP.keys().eq(LOOKUP.keys.iter()));
+    assert!(LOOKUP.values().rev().eq(LOOKUP.values.iter().rev()));
+    assert!((&LOOKUP).into_iter().eq(LOOKUP.iter()));
+}
+
+#[test]
+fn into_iter_test() {
+    let mut iter = LOOKUP.into_iter();
+
+    assert_eq!(iter.next_back(), Some(("test", "test.example.com")));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(("bye", "bye.example.com")));
+}
+
+#[test]
+fn iterating_a_reference_does_not_need_the_comparator() {
+    fn sum_values<const N: usize, K, C>(lookup: &ConstLookup<N, K, u8, C>) -> u8 {
+        let mut total = 0;
+        for (_, value) in lookup {
+            total += value;
+        }
+        total
+    }
+
+    assert_eq!(sum_values(&lookup!(2 => 20, 1 => 10)), 30);
+}
+
+#[test]
+fn values_mut_test() {
+    let mut lookup = lookup! {
+        'b' => 2,
+        'a' => 1,
+    };
+
+    for value in lookup.values_mut() {
+        *value *= 10;
+    }
+
+    assert_eq!(lookup.values, [10, 20]);
+}
+
+#[test]
+fn positional_test() {
+    use std::string::String;
+
+    let key = String::from("hey");
+    let (stored, value) = LOOKUP.get_key_value(key.as_str()).unwrap();
+
+    assert_eq!((*stored, *value), ("hey", "hey.example.com"));
+    assert_eq!(LOOKUP.get_key_value("hello"), None);
+    assert_eq!(LOOKUP.get_index_of("hey"), Some(2));
+    assert_eq!(LOOKUP.get_index_of("hello"), None);
+    assert_eq!(LOOKUP.get_index(0), Some((&"bye", &"bye.example.com")));
+    assert_eq!(LOOKUP.get_index(4), None);
+}
+
+#[test]
+fn range_test() {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+
+    assert!(LOOKUP.range::<&str, _>(..).eq(LOOKUP.iter()));
+    assert!(LOOKUP.range("c".."hey").map(|(key, _)| *key).eq(["hallo"]));
+    assert!(LOOKUP
+        .range("hallo"..="hey")
+        .map(|(key, _)| *key)
+        .eq(["hallo", "hey"]));
+    assert!(LOOKUP
+        .range::<str, _>((Excluded("hallo"), Unbounded))
+        .map(|(key, _)| *key)
+        .eq(["hey", "test"]));
+    assert!(LOOKUP
+        .range::<str, _>((Included("zzz"), Included("a")))
+        .next()
+        .is_none());
+}
+
+#[test]
+fn first_and_last_test() {
+    const EMPTY: ConstLookup<0, u8, u8> = ConstLookup::new([], []);
+
+    assert_eq!(LOOKUP.first_key_value(), Some((&"bye", &"bye.example.com")));
+    assert_eq!(
+        LOOKUP.last_key_value(),
+        Some((&"test", &"test.example.com"))
+    );
+    assert_eq!(EMPTY.first_key_value(), None);
+    assert_eq!(EMPTY.last_key_value(), None);
+}
+
+#[test]
+fn floor_and_ceiling_test() {
+    assert_eq!(LOOKUP.floor_key_value("a"), None);
+    assert_eq!(
+        LOOKUP.floor_key_value("bye"),
+        Some((&"bye", &"bye.example.com"))
+    );
+    assert_eq!(
+        LOOKUP.floor_key_value("zzz"),
+        Some((&"test", &"test.example.com"))
+    );
+    assert_eq!(
+        LOOKUP.ceiling_key_value("a"),
+        Some((&"bye", &"bye.example.com"))
+    );
+    assert_eq!(
+        LOOKUP.ceiling_key_value("hex"),
+        Some((&"hey", &"hey.example.com"))
+    );
+    assert_eq!(LOOKUP.ceiling_key_value("zzz"), None);
+}
+
+#[test]
+fn borrowed_key_test() {
+    use std::string::String;
+
+    let key = String::from("hallo");
+
+    assert_eq!(LOOKUP.get(key.as_str()), Some(&"hallo.example.com"));
+    assert!(LOOKUP.contains_key(key.as_str()));
+    assert!(!LOOKUP.contains_key("hello"));
+    assert_eq!(LOOKUP[key.as_str()], "hallo.example.com");
+}
+
 #[test]
 fn const_func() {
     assert!(!large())
@@ -208,10 +1026,10 @@ const LOOKUP_MACRO: ConstLookup<3, &str, &str> = lookup! {
 #[test]
 fn lookup_macro_works_for_const() {
     assert_eq!(
-        ConstLookup {
-            keys: ["best", "test", "guessed"],
-            values: ["better", "testing", "guessing"]
-        },
+        ConstLookup::new(
+            ["best", "guessed", "test"],
+            ["better", "guessing", "testing"]
+        ),
         LOOKUP_MACRO
     );
 }
@@ -225,10 +1043,489 @@ fn lookup_macro_works_for_normal_env() {
     };
 
     assert_eq!(
-        ConstLookup {
-            keys: ["best", "test", "guessed"],
-            values: ["better", "testing", "guessing"]
-        },
+        ConstLookup::new(
+            ["best", "guessed", "test"],
+            ["better", "guessing", "testing"]
+        ),
         lookup
     );
 }
+
+lookup! {
+    /// Declared with the length counted by the macro.
+    #[cfg(test)]
+    const LOOKUP_DECLARED: &str => &str = {
+        "test" => "testing",
+        "best" => "better",
+        "guessed" => "guessing",
+    };
+
+    #[cfg(test)]
+    pub(crate) static LOOKUP_DECLARED_STATIC: u8 => char = {
+        2 => 'b',
+        1 => 'a',
+    };
+
+    #[cfg(test)]
+    const LOOKUP_DECLARED_EMPTY: u8 => u8 = {};
+}
+
+#[test]
+fn lookup_macro_declaring_form() {
+    let declared: &ConstLookup<3, &str, &str> = &LOOKUP_DECLARED;
+
+    assert_eq!(declared, &LOOKUP_MACRO);
+    assert_eq!(LOOKUP_DECLARED_STATIC.keys, [1, 2]);
+    assert!(LOOKUP_DECLARED_EMPTY.is_empty());
+}
+
+#[test]
+fn lookup_macro_get_works_for_unsorted_entries() {
+    assert_eq!(LOOKUP_MACRO.get(&"guessed"), Some(&"guessing"));
+    assert!(LOOKUP_MACRO.check_sorted());
+}
+
+#[test]
+fn lookup_macro_sorts_primitive_keys() {
+    const NUMBERS: ConstLookup<4, i32, &str> = lookup! {
+        3 => "three",
+        -1 => "minus one",
+        300 => "three hundred",
+        0 => "zero",
+    };
+    const CHARS: ConstLookup<3, char, u8> = lookup! {
+        'c' => 3,
+        'a' => 1,
+        'b' => 2,
+    };
+    const BOOLS: ConstLookup<2, bool, &str> = lookup! {
+        true => "yes",
+        false => "no",
+    };
+
+    assert_eq!(NUMBERS.keys, [-1, 0, 3, 300]);
+    assert_eq!(
+        NUMBERS.values,
+        ["minus one", "zero", "three", "three hundred"]
+    );
+    assert_eq!(CHARS.keys, ['a', 'b', 'c']);
+    assert_eq!(CHARS.values, [1, 2, 3]);
+    assert_eq!(BOOLS[&false], "no");
+    assert_eq!(BOOLS[&true], "yes");
+}
+
+#[test]
+fn lookup_macro_unsorted_works_for_other_keys() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    enum Level {
+        Debug,
+        Info,
+        Error,
+    }
+
+    const LEVELS: ConstLookup<3, Level, u8> = lookup! {
+        unsorted;
+        Level::Debug => 10,
+        Level::Info => 20,
+        Level::Error => 40,
+    };
+    const OPTIONS: ConstLookup<2, Option<u8>, &str> =
+        lookup!(unsorted; None => "none", Some(1) => "one");
+
+    assert!(LEVELS.check_sorted());
+    assert_eq!(LEVELS.get(&Level::Info), Some(&20));
+    assert_eq!(LEVELS[&Level::Error], 40);
+    assert_eq!(OPTIONS.get(&None), Some(&"none"));
+}
+
+#[test]
+fn lookup_macro_unsorted_declares_items() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    enum Level {
+        Debug,
+        Info,
+        Error,
+    }
+
+    lookup! {
+        unsorted;
+        const LEVELS: Level => u8 = {
+            Level::Debug => 10,
+            Level::Info => 20,
+            Level::Error => 40,
+        };
+        static NAMES: Level => &str = {
+            Level::Debug => "debug",
+            Level::Error => "error",
+        };
+    }
+
+    assert!(LEVELS.check_sorted());
+    assert_eq!(LEVELS.get(&Level::Info), Some(&20));
+    assert_eq!(NAMES.get(&Level::Error), Some(&"error"));
+    assert_eq!(NAMES.get(&Level::Info), None);
+}
+
+#[test]
+fn const_get_works_in_const_items() {
+    const HEY: &str = match LOOKUP.const_get(&"hey") {
+        Some(value) => value,
+        None => panic!("missing key"),
+    };
+    const STATUS: ConstLookup<3, u16, &str> = lookup! {
+        404 => "Not Found",
+        200 => "OK",
+        500 => "Internal Server Error",
+    };
+    const OK_LEN: usize = STATUS.const_get(&200).unwrap().len();
+
+    assert_eq!(HEY, "hey.example.com");
+    const { assert!(LOOKUP.const_contains_key(&"test")) };
+    const { assert!(!LOOKUP.const_contains_key(&"hello")) };
+    assert_eq!([0u8; OK_LEN].len(), 2);
+    assert_eq!(STATUS.const_get(&418), None);
+    for (code, message) in STATUS.keys.iter().zip(STATUS.values) {
+        assert_eq!(STATUS.const_get(code), Some(&message));
+    }
+}
+
+#[test]
+fn new_checked_accepts_sorted_keys() {
+    const CHECKED: ConstLookup<4, &str, &str> =
+        ConstLookup::new_checked(LOOKUP.keys, LOOKUP.values);
+
+    assert_eq!(CHECKED, LOOKUP);
+}
+
+#[test]
+#[should_panic(expected = "not sorted or contain duplicates")]
+fn new_checked_rejects_duplicate_keys() {
+    ConstLookup::new_checked([1, 2, 2], ["one", "two", "two again"]);
+}
+
+#[test]
+#[should_panic(expected = "not sorted or contain duplicates")]
+fn lookup_macro_rejects_duplicate_keys() {
+    lookup! {
+        "best" => "better",
+        "test" => "testing",
+        "best" => "bestest",
+    };
+}
+
+#[cfg(test)]
+static LOOKUP_STATIC: ConstLookup<3, u8, &str> = lookup! {
+    2 => "two",
+    1 => "one",
+    3 => "three",
+};
+
+#[test]
+fn lookup_macro_works_for_static() {
+    assert_eq!(LOOKUP_STATIC.keys, [1, 2, 3]);
+    assert_eq!(LOOKUP_STATIC[&3], "three");
+}
+
+#[test]
+fn lookup_macro_works_for_references() {
+    let (one, two) = (1u64, 2u64);
+    let lookup = lookup! {
+        "two" => &two,
+        "one" => &one,
+    };
+
+    assert_eq!(lookup.get(&"one"), Some(&&1));
+    assert_eq!(lookup.get(&"two"), Some(&&2));
+}
+
+#[test]
+fn lookup_macro_works_for_integers() {
+    let lookup = lookup! {
+        u64::MAX => i8::MIN,
+        0 => 0,
+        42 => i8::MAX,
+    };
+
+    assert_eq!(lookup.keys, [0, 42, u64::MAX]);
+    assert_eq!(lookup.values, [0, i8::MAX, i8::MIN]);
+}
+
+#[test]
+fn lookup_macro_works_for_drop_glue() {
+    use std::string::String;
+
+    const EMPTY: ConstLookup<2, char, String> = lookup! {
+        'b' => String::new(),
+        'a' => String::new(),
+    };
+    let lookup = lookup! {
+        "b" => String::from("bee"),
+        "a" => String::from("ay"),
+        "c" => String::from("see"),
+    };
+
+    assert_eq!(EMPTY[&'a'], "");
+    assert_eq!(lookup.values, ["ay", "bee", "see"]);
+}
+
+#[test]
+fn lookup_macro_drops_every_value_once() {
+    use core::cell::Cell;
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let lookup = lookup! {
+        3 => DropCounter(&drops),
+        1 => DropCounter(&drops),
+        2 => DropCounter(&drops),
+    };
+    assert_eq!(drops.get(), 0);
+
+    drop(lookup);
+    assert_eq!(drops.get(), 3);
+}
+
+#[cfg(test)]
+const TUPLE_LOOKUP: ConstLookup<6, (&str, u16, char), u8> = lookup! {
+    ("us", 2, 'b') => 4,
+    ("de", 1, 'a') => 1,
+    ("us", 1, 'a') => 2,
+    ("fr", 1, 'a') => 0,
+    ("us", 1, 'b') => 3,
+    ("ch", 3, 'a') => 5,
+};
+
+#[test]
+fn tuple_keys_test() {
+    assert!(TUPLE_LOOKUP.check_sorted());
+    assert_eq!(TUPLE_LOOKUP.keys[0], ("ch", 3, 'a'));
+    assert_eq!(TUPLE_LOOKUP.get(&("us", 1, 'b')), Some(&3));
+    assert_eq!(TUPLE_LOOKUP.get(&("us", 3, 'b')), None);
+    const { assert!(TUPLE_LOOKUP.const_contains_key(&("fr", 1, 'a'))) };
+}
+
+#[test]
+fn prefix_range_test() {
+    assert!(TUPLE_LOOKUP
+        .prefix_range(&"us")
+        .map(|(_, v)| *v)
+        .eq([2, 3, 4]));
+    assert!(TUPLE_LOOKUP
+        .prefix_range(&("us", 1))
+        .map(|(_, v)| *v)
+        .eq([2, 3]));
+    assert!(TUPLE_LOOKUP.prefix_range(&"de").map(|(_, v)| *v).eq([1]));
+    assert_eq!(TUPLE_LOOKUP.prefix_range(&"it").len(), 0);
+    assert_eq!(TUPLE_LOOKUP.prefix_range(&"zz").len(), 0);
+}
+
+#[cfg(test)]
+const ROUTES: ConstLookup<6, &str, u8> = lookup! {
+    "/" => 0,
+    "/api" => 1,
+    "/api/users" => 2,
+    "/api/v2" => 3,
+    "/assets" => 4,
+    "/ä" => 5,
+};
+
+#[test]
+fn longest_prefix_match_test() {
+    assert_eq!(
+        ROUTES.longest_prefix_match("/api/users/7"),
+        Some((&"/api/users", &2))
+    );
+    assert_eq!(
+        ROUTES.longest_prefix_match("/api/user"),
+        Some((&"/api", &1))
+    );
+    assert_eq!(ROUTES.longest_prefix_match("/api"), Some((&"/api", &1)));
+    assert_eq!(ROUTES.longest_prefix_match("/b"), Some((&"/", &0)));
+    assert_eq!(ROUTES.longest_prefix_match("/äb"), Some((&"/ä", &5)));
+    assert_eq!(ROUTES.longest_prefix_match("/å"), Some((&"/", &0)));
+    assert_eq!(ROUTES.longest_prefix_match(""), None);
+    assert_eq!(ROUTES.longest_prefix_match("api"), None);
+}
+
+#[test]
+fn keys_with_prefix_test() {
+    assert!(ROUTES
+        .keys_with_prefix("/api")
+        .map(|(_, v)| *v)
+        .eq([1, 2, 3]));
+    assert!(ROUTES
+        .keys_with_prefix("/a")
+        .map(|(_, v)| *v)
+        .eq([1, 2, 3, 4]));
+    assert_eq!(ROUTES.keys_with_prefix("").len(), ROUTES.len());
+    assert_eq!(ROUTES.keys_with_prefix("/b").len(), 0);
+    assert_eq!(ROUTES.keys_with_prefix("/api/v3").len(), 0);
+}
+
+#[cfg(test)]
+const HEADERS: ConstLookup<3, &UncasedStr, u8> = lookup! {
+    UncasedStr::new("X-Request-Id") => 3,
+    UncasedStr::new("content-type") => 1,
+    UncasedStr::new("Accept") => 0,
+};
+
+#[test]
+fn uncased_keys_test() {
+    assert!(HEADERS.keys.iter().map(|key| key.as_str()).eq([
+        "Accept",
+        "content-type",
+        "X-Request-Id"
+    ]));
+    assert!(HEADERS.check_sorted());
+    assert_eq!(HEADERS.get(UncasedStr::new("Content-Type")), Some(&1));
+    assert_eq!(HEADERS.get(UncasedStr::new("x-request-id")), Some(&3));
+    assert_eq!(HEADERS.get(UncasedStr::new("Content-Length")), None);
+    const { assert!(HEADERS.const_contains_key(&UncasedStr::new("ACCEPT"))) };
+}
+
+#[test]
+#[should_panic(expected = "keys of ConstLookup are not sorted or contain duplicates")]
+fn uncased_keys_reject_case_duplicates() {
+    lookup! {
+        UncasedStr::new("Accept") => 0,
+        UncasedStr::new("accept") => 1,
+    };
+}
+
+#[cfg(test)]
+const FLOATS: ConstLookup<5, f64, &str, TotalCmp> = lookup! {
+    cmp: TotalCmp;
+    1.5 => "one and a half",
+    -0.0 => "negative zero",
+    f64::NEG_INFINITY => "negative infinity",
+    0.0 => "zero",
+    -2.0 => "minus two",
+};
+
+#[cfg(test)]
+struct ReverseCmp;
+
+#[cfg(test)]
+impl<K: ?Sized + Ord> Comparator<K> for ReverseCmp {
+    fn cmp(a: &K, b: &K) -> Ordering {
+        b.cmp(a)
+    }
+}
+
+#[test]
+fn total_cmp_keys_test() {
+    assert_eq!(FLOATS.keys, [f64::NEG_INFINITY, -2.0, -0.0, 0.0, 1.5]);
+    assert!(FLOATS.check_sorted());
+    assert_eq!(FLOATS.get(&-0.0), Some(&"negative zero"));
+    assert_eq!(FLOATS.get(&0.0), Some(&"zero"));
+    assert_eq!(FLOATS.get(&f64::NAN), None);
+    assert!(FLOATS
+        .range(-3.0..0.0)
+        .map(|(_, v)| *v)
+        .eq(["minus two", "negative zero"]));
+    const { assert!(FLOATS.const_contains_key(&1.5)) };
+}
+
+#[test]
+fn custom_comparator_test() {
+    const REVERSED: ConstLookup<3, u8, char, ReverseCmp> =
+        ConstLookup::with_comparator([3, 2, 1], ['c', 'b', 'a']);
+    const WRONG: ConstLookup<3, u8, char, ReverseCmp> =
+        ConstLookup::with_comparator([1, 2, 3], ['a', 'b', 'c']);
+
+    assert!(REVERSED.check_sorted());
+    assert!(!WRONG.check_sorted());
+    assert_eq!(REVERSED.get(&1), Some(&'a'));
+    assert_eq!(REVERSED[&3], 'c');
+    assert_eq!(REVERSED.floor_key_value(&5), None);
+    assert_eq!(REVERSED.floor_key_value(&2), Some((&2, &'b')));
+    assert!(REVERSED.range(2..).map(|(k, _)| *k).eq([2, 1]));
+    assert_eq!(
+        REVERSED,
+        ConstLookup::with_comparator([3, 2, 1], ['c', 'b', 'a'])
+    );
+    assert_eq!(
+        std::format!("{REVERSED:?}"),
+        "ConstLookup { keys: [3, 2, 1], values: ['c', 'b', 'a'] }"
+    );
+}
+
+#[test]
+fn lookup_macro_unsorted_with_comparator() {
+    const REVERSED: ConstLookup<3, u8, char, ReverseCmp> = lookup! {
+        unsorted; cmp: ReverseCmp;
+        3 => 'c',
+        2 => 'b',
+        1 => 'a',
+    };
+
+    assert!(REVERSED.check_sorted());
+    assert_eq!(REVERSED.get(&2), Some(&'b'));
+}
+
+#[test]
+fn lookup_macro_unsorted_declares_items_with_comparator() {
+    lookup! {
+        unsorted;
+        const REVERSED: u8 => char, ReverseCmp = {
+            3 => 'c',
+            2 => 'b',
+            1 => 'a',
+        };
+        static WORDS: &str => u8, ReverseCmp = {
+            "two" => 2,
+            "one" => 1,
+        };
+    }
+
+    assert!(REVERSED.check_sorted());
+    assert_eq!(REVERSED.keys, [3, 2, 1]);
+    assert_eq!(REVERSED.get(&2), Some(&'b'));
+    assert!(WORDS.check_sorted());
+    assert_eq!(WORDS.get("one"), Some(&1));
+}
+
+lookup! {
+    #[cfg(test)]
+    const FLOATS_DECLARED: f32 => u8, TotalCmp = {
+        2.5 => 2,
+        -1.0 => 1,
+    };
+}
+
+#[test]
+fn lookup_macro_declaring_form_with_comparator() {
+    assert_eq!(FLOATS_DECLARED.keys, [-1.0, 2.5]);
+    assert_eq!(FLOATS_DECLARED.get(&2.5), Some(&2));
+}
+
+#[test]
+fn small_and_large_lookups_search_the_same() {
+    fn check<const N: usize>() {
+        let mut keys = [0u64; N];
+        for (i, key) in keys.iter_mut().enumerate() {
+            *key = i as u64 * 2 + 1;
+        }
+        let lookup = ConstLookup::new(keys, keys);
+
+        for key in 0..=2 * N as u64 + 1 {
+            let expected = keys.binary_search(&key).ok();
+            assert_eq!(lookup.get_index_of(&key), expected);
+            assert_eq!(lookup.contains_key(&key), expected.is_some());
+        }
+    }
+
+    // eight `u64` keys are searched linearly, nine are searched with a binary search
+    check::<0>();
+    check::<1>();
+    check::<8>();
+    check::<9>();
+    check::<40>();
+}
@@ -0,0 +1,140 @@
+use core::borrow::Borrow;
+
+use crate::key::{self, ConstKey};
+use crate::{is_sorted, iter, partition, OrdCmp};
+
+/// Map that can be defined in a const context and allows the same key more than once.
+///
+/// The entries are sorted by key, entries with the same key are next to each other. [`get_all`](ConstMultiLookup::get_all)
+/// returns the values of all of them as a slice.
+///
+/// ```rust
+/// use const_lookup_map::{ConstMultiLookup, multi_lookup};
+///
+/// const ALIASES: ConstMultiLookup<4, &str, &str> = multi_lookup! {
+///     "rm" => "remove",
+///     "ls" => "list",
+///     "rm" => "delete",
+///     "dir" => "list",
+/// };
+///
+/// assert_eq!(ALIASES.get_all("rm"), ["remove", "delete"]);
+/// assert_eq!(ALIASES.get_all("ls"), ["list"]);
+/// assert!(ALIASES.get_all("cd").is_empty());
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstMultiLookup<const N: usize, K: Ord, V> {
+    pub keys: [K; N],
+    pub values: [V; N],
+}
+
+impl<const N: usize, K: Ord, V> ConstMultiLookup<N, K, V> {
+    /// Returns the number of elements in the map, every value of a key is counted.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the map contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub const fn new(keys: [K; N], values: [V; N]) -> ConstMultiLookup<N, K, V> {
+        ConstMultiLookup { keys, values }
+    }
+
+    /// Returns true if the keys are sorted, duplicate keys are allowed.
+    pub fn check_sorted(&self) -> bool {
+        is_sorted::<OrdCmp, _>(&self.keys)
+    }
+
+    /// Returns the values corresponding to the key, in the order they were written in when created with
+    /// [`multi_lookup!`](crate::multi_lookup).
+    pub fn get_all<Q>(&self, key: &Q) -> &[V]
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let start = partition::<OrdCmp, _, _>(&self.keys, key, false);
+        let end = partition::<OrdCmp, _, _>(&self.keys, key, true);
+        &self.values[start..end]
+    }
+
+    /// Returns a reference to the first value corresponding to the key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.get_all(key).first()
+    }
+
+    /// Returns true if the map contains a value for the specified key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        !self.get_all(key).is_empty()
+    }
+
+    /// Returns an iterator over the entries of the map, in key order.
+    pub fn iter(&self) -> iter::Iter<'_, K, V> {
+        iter::Iter::new(&self.keys, &self.values)
+    }
+}
+
+impl<const N: usize, K: Ord + ConstKey, V> ConstMultiLookup<N, K, V> {
+    /// Sorts the entries by key, this can be used in a const context.
+    ///
+    /// The sort is stable, values of the same key keep their order.
+    pub const fn sorted(mut self) -> Self {
+        key::sort_entries(&mut self.keys, &mut self.values);
+        self
+    }
+}
+
+impl<'a, const N: usize, K: Ord, V> IntoIterator for &'a ConstMultiLookup<N, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = iter::Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+const MULTI_LOOKUP: ConstMultiLookup<6, u8, &str> = crate::multi_lookup! {
+    2 => "two",
+    1 => "one",
+    2 => "deux",
+    3 => "three",
+    2 => "zwei",
+    1 => "un",
+};
+
+#[test]
+fn multi_lookup_sorts_stable() {
+    assert_eq!(MULTI_LOOKUP.keys, [1, 1, 2, 2, 2, 3]);
+    assert_eq!(
+        MULTI_LOOKUP.values,
+        ["one", "un", "two", "deux", "zwei", "three"]
+    );
+    assert!(MULTI_LOOKUP.check_sorted());
+}
+
+#[test]
+fn multi_lookup_get_all_test() {
+    assert_eq!(MULTI_LOOKUP.get_all(&2), ["two", "deux", "zwei"]);
+    assert_eq!(MULTI_LOOKUP.get_all(&3), ["three"]);
+    assert!(MULTI_LOOKUP.get_all(&4).is_empty());
+    assert!(MULTI_LOOKUP.get_all(&0).is_empty());
+}
+
+#[test]
+fn multi_lookup_get_test() {
+    assert_eq!(MULTI_LOOKUP.get(&1), Some(&"one"));
+    assert_eq!(MULTI_LOOKUP.get(&4), None);
+    assert!(MULTI_LOOKUP.contains_key(&3));
+    assert!(!MULTI_LOOKUP.contains_key(&0));
+}
@@ -0,0 +1,42 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+use crate::ConstLookup;
+
+/// Serializes the lookup as a map, in key order.
+impl<const N: usize, K, V, C> Serialize for ConstLookup<N, K, V, C>
+where
+    K: Serialize,
+    V: Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(N))?;
+        for (key, value) in self.keys.iter().zip(&self.values) {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[test]
+fn serialize_test() {
+    let lookup = crate::lookup! {
+        "test" => 3,
+        "best" => 1,
+        "guessed" => 2,
+    };
+
+    assert_eq!(
+        serde_json::to_string(&lookup).unwrap(),
+        r#"{"best":1,"guessed":2,"test":3}"#
+    );
+}
+
+#[test]
+fn serialize_does_not_need_the_comparator_test() {
+    fn to_json<const N: usize, C>(lookup: &ConstLookup<N, u8, bool, C>) -> std::string::String {
+        serde_json::to_string(lookup).unwrap()
+    }
+
+    let lookup = crate::lookup!(2 => true, 1 => false);
+    assert_eq!(to_json(&lookup), r#"{"1":false,"2":true}"#);
+}
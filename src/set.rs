@@ -0,0 +1,323 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::mem::MaybeUninit;
+use core::ops::RangeBounds;
+
+use crate::key::{self, ConstKey};
+use crate::{is_sorted, iter, range_indices, OrdCmp};
+
+/// Set that can be defined in a const context, the keys are stored sorted just like in a
+/// [`ConstLookup`](crate::ConstLookup).
+///
+/// ```rust
+/// use const_lookup_map::{ConstLookupSet, set};
+///
+/// const ALLOWED: ConstLookupSet<3, &str> = set!["name", "id", "email"];
+///
+/// assert!(ALLOWED.contains("id"));
+/// assert!(!ALLOWED.contains("password"));
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConstLookupSet<const N: usize, K: Ord> {
+    pub keys: [K; N],
+}
+
+impl<const N: usize, K: Ord> ConstLookupSet<N, K> {
+    /// Returns the number of elements in the set.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns true if the set contains no elements.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    pub const fn new(keys: [K; N]) -> ConstLookupSet<N, K> {
+        ConstLookupSet { keys }
+    }
+
+    /// Returns true if the keys are sorted, see [`ConstLookup::check_sorted`](crate::ConstLookup::check_sorted).
+    pub fn check_sorted(&self) -> bool {
+        is_sorted::<OrdCmp, _>(&self.keys)
+    }
+
+    /// Returns true if the set contains the key.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the key in the set that is equal to the given key.
+    pub fn get<Q>(&self, key: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+    {
+        let index = self
+            .keys
+            .binary_search_by(|probe| probe.borrow().cmp(key))
+            .ok()?;
+        self.keys.get(index)
+    }
+
+    /// Returns an iterator over the keys of the set, in order.
+    pub fn iter(&self) -> iter::Keys<'_, K> {
+        iter::Keys {
+            inner: self.keys.iter(),
+        }
+    }
+
+    /// Returns an iterator over the keys in the range, in order.
+    ///
+    /// The iterator is empty if the start of the range is after the end.
+    pub fn range<Q, R>(&self, range: R) -> iter::Keys<'_, K>
+    where
+        K: Borrow<Q>,
+        Q: ?Sized + Ord,
+        R: RangeBounds<Q>,
+    {
+        iter::Keys {
+            inner: self.keys[range_indices::<OrdCmp, _, _, _>(&self.keys, range)].iter(),
+        }
+    }
+}
+
+impl<const N: usize, K: Ord + ConstKey> ConstLookupSet<N, K> {
+    /// Sorts the keys, this can be used in a const context.
+    pub const fn sorted(mut self) -> Self {
+        key::sort_entries(&mut self.keys, &mut [(); N]);
+        self
+    }
+
+    /// Creates the set and checks that the keys are sorted and unique.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are out of order or contain duplicates.
+    pub const fn new_checked(keys: [K; N]) -> Self {
+        ConstLookupSet::new(keys).checked()
+    }
+
+    /// Checks that the keys are sorted and unique, this can be used in a const context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keys are out of order or contain duplicates.
+    pub const fn checked(self) -> Self {
+        assert!(
+            key::is_strictly_sorted(&self.keys),
+            "keys of ConstLookupSet are not sorted or contain duplicates"
+        );
+        self
+    }
+
+    /// Returns true if the set contains the key, this can be used in a const context.
+    pub const fn const_contains(&self, key: &K) -> bool {
+        key::binary_search(&self.keys, key).is_ok()
+    }
+
+    /// Returns true if every key of this set is also in `other`, this can be used in a const context.
+    pub const fn is_subset<const M: usize>(&self, other: &ConstLookupSet<M, K>) -> bool {
+        self.intersection_len(other) == N
+    }
+
+    /// Returns true if every key of `other` is also in this set, this can be used in a const context.
+    pub const fn is_superset<const M: usize>(&self, other: &ConstLookupSet<M, K>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns the number of keys that are in this set, in `other` or in both.
+    pub const fn union_len<const M: usize>(&self, other: &ConstLookupSet<M, K>) -> usize {
+        N + M - self.intersection_len(other)
+    }
+
+    /// Returns the number of keys that are in both this set and `other`.
+    pub const fn intersection_len<const M: usize>(&self, other: &ConstLookupSet<M, K>) -> usize {
+        let (mut i, mut j, mut len) = (0, 0, 0);
+        while i < N && j < M {
+            match key::cmp(&self.keys[i], &other.keys[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    len += 1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        len
+    }
+}
+
+impl<const N: usize, K: Ord + ConstKey + Copy> ConstLookupSet<N, K> {
+    /// Returns the set with the keys that are in this set, in `other` or in both, this can be used in a const context.
+    ///
+    /// The length `O` of the new set can be calculated with [`union_len`](ConstLookupSet::union_len).
+    ///
+    /// ```rust
+    /// use const_lookup_map::{ConstLookupSet, set};
+    ///
+    /// const READ: ConstLookupSet<2, u8> = set![1, 2];
+    /// const WRITE: ConstLookupSet<2, u8> = set![2, 3];
+    /// const ALL: ConstLookupSet<{ READ.union_len(&WRITE) }, u8> = READ.union(&WRITE);
+    ///
+    /// assert_eq!(ALL.keys, [1, 2, 3]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `O` is not the length of the union.
+    pub const fn union<const M: usize, const O: usize>(
+        &self,
+        other: &ConstLookupSet<M, K>,
+    ) -> ConstLookupSet<O, K> {
+        assert!(
+            self.union_len(other) == O,
+            "length of the union does not match the length of the ConstLookupSet"
+        );
+        let mut keys = [const { MaybeUninit::uninit() }; O];
+        let (mut i, mut j, mut len) = (0, 0, 0);
+        while i < N || j < M {
+            let ordering = if i == N {
+                Ordering::Greater
+            } else if j == M {
+                Ordering::Less
+            } else {
+                key::cmp(&self.keys[i], &other.keys[j])
+            };
+            keys[len] = match ordering {
+                Ordering::Less => {
+                    i += 1;
+                    MaybeUninit::new(self.keys[i - 1])
+                }
+                Ordering::Greater => {
+                    j += 1;
+                    MaybeUninit::new(other.keys[j - 1])
+                }
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                    MaybeUninit::new(self.keys[i - 1])
+                }
+            };
+            len += 1;
+        }
+        // SAFETY: the length check above makes sure every key is initialized.
+        ConstLookupSet::new(unsafe { assume_init(&keys) })
+    }
+
+    /// Returns the set with the keys that are in both this set and `other`, this can be used in a const context.
+    ///
+    /// The length `O` of the new set can be calculated with [`intersection_len`](ConstLookupSet::intersection_len).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `O` is not the length of the intersection.
+    pub const fn intersection<const M: usize, const O: usize>(
+        &self,
+        other: &ConstLookupSet<M, K>,
+    ) -> ConstLookupSet<O, K> {
+        assert!(
+            self.intersection_len(other) == O,
+            "length of the intersection does not match the length of the ConstLookupSet"
+        );
+        let mut keys = [const { MaybeUninit::uninit() }; O];
+        let (mut i, mut j, mut len) = (0, 0, 0);
+        while i < N && j < M {
+            match key::cmp(&self.keys[i], &other.keys[j]) {
+                Ordering::Less => i += 1,
+                Ordering::Greater => j += 1,
+                Ordering::Equal => {
+                    keys[len] = MaybeUninit::new(self.keys[i]);
+                    len += 1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        // SAFETY: the length check above makes sure every key is initialized.
+        ConstLookupSet::new(unsafe { assume_init(&keys) })
+    }
+}
+
+/// # Safety
+///
+/// Every element of `keys` must be initialized.
+const unsafe fn assume_init<const N: usize, K: Copy>(keys: &[MaybeUninit<K>; N]) -> [K; N] {
+    (keys as *const [MaybeUninit<K>; N] as *const [K; N]).read()
+}
+
+impl<'a, const N: usize, K: Ord> IntoIterator for &'a ConstLookupSet<N, K> {
+    type Item = &'a K;
+    type IntoIter = iter::Keys<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+const SET: ConstLookupSet<4, &str> = crate::set!["test", "bye", "hey", "hallo"];
+
+#[cfg(test)]
+const EVEN: ConstLookupSet<4, u32> = crate::set![8, 2, 6, 4];
+
+#[cfg(test)]
+const SMALL: ConstLookupSet<3, u32> = crate::set![1, 2, 3];
+
+#[test]
+fn set_macro_sorts_keys() {
+    assert_eq!(SET.keys, ["bye", "hallo", "hey", "test"]);
+    assert!(SET.check_sorted());
+}
+
+#[test]
+fn set_contains_test() {
+    assert!(SET.contains("hey"));
+    assert!(SET.contains(&"hey"));
+    assert!(!SET.contains("hello"));
+    assert_eq!(SET.get("bye"), Some(&"bye"));
+    const { assert!(EVEN.const_contains(&4)) };
+}
+
+#[test]
+fn set_iter_and_range_test() {
+    assert!(EVEN.iter().eq(&[2, 4, 6, 8]));
+    assert!(EVEN.iter().rev().eq(&[8, 6, 4, 2]));
+    assert!(EVEN.range(3..=6).eq(&[4, 6]));
+    assert!((&SET).into_iter().eq(SET.iter()));
+}
+
+#[test]
+fn set_subset_test() {
+    const TWO: ConstLookupSet<2, u32> = crate::set![2, 4];
+
+    const { assert!(TWO.is_subset(&EVEN)) };
+    const { assert!(EVEN.is_superset(&TWO)) };
+    assert!(!SMALL.is_subset(&EVEN));
+}
+
+#[test]
+fn set_union_and_intersection_test() {
+    const UNION: ConstLookupSet<{ SMALL.union_len(&EVEN) }, u32> = SMALL.union(&EVEN);
+    const INTERSECTION: ConstLookupSet<{ SMALL.intersection_len(&EVEN) }, u32> =
+        SMALL.intersection(&EVEN);
+    const NOTHING: ConstLookupSet<0, u32> = SMALL.intersection(&crate::set![7]);
+
+    let union: ConstLookupSet<6, u32> = SMALL.union(&EVEN);
+
+    assert_eq!(union, UNION);
+    assert_eq!(UNION.keys, [1, 2, 3, 4, 6, 8]);
+    assert_eq!(INTERSECTION.keys, [2]);
+    assert!(NOTHING.is_empty());
+}
+
+#[test]
+#[should_panic(expected = "length of the union does not match")]
+fn set_union_rejects_wrong_length() {
+    let _: ConstLookupSet<7, u32> = SMALL.union(&EVEN);
+}
@@ -0,0 +1,89 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::key;
+
+/// String slice that is compared and hashed ignoring ASCII case, for keys like HTTP header names.
+///
+/// A `&UncasedStr` can be used as key of every lookup, the keys are sorted and looked up with the same
+/// case-insensitive order, so [`check_sorted`](crate::ConstLookup::check_sorted) checks that order too.
+///
+/// ```rust
+/// use const_lookup_map::{ConstLookup, UncasedStr, lookup};
+///
+/// const HEADERS: ConstLookup<2, &UncasedStr, u8> = lookup! {
+///     UncasedStr::new("Content-Type") => 1,
+///     UncasedStr::new("accept") => 2,
+/// };
+///
+/// assert_eq!(HEADERS.get(UncasedStr::new("content-type")), Some(&1));
+/// assert_eq!(HEADERS.get(UncasedStr::new("ACCEPT")), Some(&2));
+/// assert!(HEADERS.check_sorted());
+/// ```
+#[repr(transparent)]
+pub struct UncasedStr(str);
+
+impl UncasedStr {
+    /// Wraps a string slice, this can be used in a const context.
+    pub const fn new(value: &str) -> &UncasedStr {
+        // SAFETY: `UncasedStr` is a `repr(transparent)` wrapper around `str`.
+        unsafe { &*(value as *const str as *const UncasedStr) }
+    }
+
+    /// Returns the wrapped string slice with its original case.
+    pub const fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'a> From<&'a str> for &'a UncasedStr {
+    fn from(value: &'a str) -> Self {
+        UncasedStr::new(value)
+    }
+}
+
+impl PartialEq for UncasedStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+impl Eq for UncasedStr {}
+
+impl PartialOrd for UncasedStr {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for UncasedStr {
+    fn cmp(&self, other: &Self) -> Ordering {
+        key::cmp_str(&self.0, &other.0, true)
+    }
+}
+
+impl fmt::Debug for UncasedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for UncasedStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[test]
+fn uncased_str_ignores_ascii_case() {
+    assert_eq!(
+        UncasedStr::new("Content-Type"),
+        UncasedStr::new("content-TYPE")
+    );
+    assert_ne!(UncasedStr::new("Straße"), UncasedStr::new("STRASSE"));
+    assert_eq!(
+        UncasedStr::new("a").cmp(UncasedStr::new("B")),
+        Ordering::Less
+    );
+    assert_eq!(UncasedStr::new("Accept").as_str(), "Accept");
+}